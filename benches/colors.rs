@@ -0,0 +1,20 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use lightning_time::{LightningStep, LightningTime, LightningTimeColorConfig};
+
+fn colors(c: &mut Criterion) {
+    let config = LightningTimeColorConfig::default();
+    let times: Vec<_> = LightningTime::iter_day(LightningStep::Charge).collect();
+
+    c.bench_function("colors", |b| {
+        b.iter(|| {
+            for time in &times {
+                black_box(black_box(time).colors(&config));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, colors);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+use std::hint::black_box;
+
+use chrono::NaiveTime;
+use criterion::{criterion_group, criterion_main, Criterion};
+use lightning_time::LightningTime;
+
+fn from_naive_times(c: &mut Criterion) {
+    let times: Vec<_> = (0..86_400)
+        .map(|s| NaiveTime::from_num_seconds_from_midnight_opt(s, 0).unwrap())
+        .collect();
+    let mut out = vec![LightningTime::default(); times.len()];
+
+    c.bench_function("from_naive_times", |b| {
+        b.iter(|| LightningTime::from_naive_times(black_box(&times), &mut out))
+    });
+    c.bench_function("from_naive_time", |b| {
+        b.iter(|| {
+            for (time, out) in black_box(&times).iter().zip(&mut out) {
+                *out = LightningTime::from(*time);
+            }
+        })
+    });
+}
+
+criterion_group!(benches, from_naive_times);
+criterion_main!(benches);
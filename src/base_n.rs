@@ -0,0 +1,222 @@
+#[cfg(feature = "chrono")]
+use chrono::NaiveTime;
+
+#[cfg(feature = "chrono")]
+use crate::naive_nanos;
+use crate::{Error, NANOS_PER_DAY};
+
+/// An experimental variant of [`LightningTime`](crate::LightningTime) that divides each level into
+/// `BASE` units instead of 16, e.g. `LightningTimeBaseN<10>` for a decimal day. `BASE` must be
+/// within `2..=16`, which is checked at compile time by every constructor:
+///
+/// ```compile_fail
+/// # use lightning_time::LightningTimeBaseN;
+/// let time = LightningTimeBaseN::<20>::new(19, 0, 0, 0, 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LightningTimeBaseN<const BASE: u8> {
+    pub bolts: u8,
+    pub zaps: u8,
+    pub sparks: u8,
+    pub charges: u8,
+    pub subcharges: u8,
+}
+
+impl<const BASE: u8> LightningTimeBaseN<BASE> {
+    const VALID_BASE: () = assert!(2 <= BASE && BASE <= 16, "BASE must be within 2..=16");
+
+    /// The number of subcharges in a day, `BASE`^5.
+    pub const SUBCHARGES_PER_DAY: u32 = {
+        let _: () = Self::VALID_BASE;
+        (BASE as u32).pow(5)
+    };
+
+    pub const fn new(bolts: u8, zaps: u8, sparks: u8, charges: u8, subcharges: u8) -> Self {
+        let _: () = Self::VALID_BASE;
+        Self {
+            bolts,
+            zaps,
+            sparks,
+            charges,
+            subcharges,
+        }
+    }
+
+    /// Like [`LightningTimeBaseN::new`], but fails if any component is not a single digit in `BASE`.
+    pub fn try_new(
+        bolts: u8,
+        zaps: u8,
+        sparks: u8,
+        charges: u8,
+        subcharges: u8,
+    ) -> Result<Self, Error> {
+        let time = Self::new(bolts, zaps, sparks, charges, subcharges);
+        time.validate()?;
+        Ok(time)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        for (component, value) in [
+            ("bolts", self.bolts),
+            ("zaps", self.zaps),
+            ("sparks", self.sparks),
+            ("charges", self.charges),
+            ("subcharges", self.subcharges),
+        ] {
+            if value >= BASE {
+                return Err(Error::DigitOutOfRange { component, value });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The number of subcharges elapsed since midnight.
+    pub fn as_subcharges(&self) -> u32 {
+        [self.zaps, self.sparks, self.charges, self.subcharges]
+            .into_iter()
+            .fold(self.bolts as u32, |elapsed, digit| {
+                elapsed * BASE as u32 + digit as u32
+            })
+    }
+
+    /// Creates a time from the number of subcharges elapsed since midnight.
+    pub fn from_subcharges(n: u32) -> Result<Self, Error> {
+        if n >= Self::SUBCHARGES_PER_DAY {
+            return Err(Error::OutOfDayRange);
+        }
+
+        let base = BASE as u32;
+        let mut digits = [0; 5];
+        let mut rest = n;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % base) as u8;
+            rest /= base;
+        }
+        let [bolts, zaps, sparks, charges, subcharges] = digits;
+
+        Ok(Self::new(bolts, zaps, sparks, charges, subcharges))
+    }
+
+    /// Creates a time from nanoseconds since midnight, which must be within `[0, 24h)`. Rounds
+    /// down to the subcharge.
+    pub fn from_nanos_of_day(nanos: i128) -> Result<Self, Error> {
+        if !(0..NANOS_PER_DAY).contains(&nanos) {
+            return Err(Error::OutOfDayRange);
+        }
+
+        Self::from_subcharges((nanos * Self::SUBCHARGES_PER_DAY as i128 / NANOS_PER_DAY) as u32)
+    }
+
+    /// The nanoseconds since midnight represented by this time, rounded down.
+    pub fn nanos_of_day(&self) -> i128 {
+        self.as_subcharges() as i128 * NANOS_PER_DAY / Self::SUBCHARGES_PER_DAY as i128
+    }
+
+    /// Converts to a [`NaiveTime`], failing if any component is not a single digit in `BASE`.
+    #[cfg(feature = "chrono")]
+    pub fn try_into_naive_time(&self) -> Result<NaiveTime, Error> {
+        self.validate()?;
+
+        let nanos = self.nanos_of_day();
+        NaiveTime::from_num_seconds_from_midnight_opt(
+            (nanos / 1_000_000_000) as u32,
+            (nanos % 1_000_000_000) as u32,
+        )
+        .ok_or(Error::OutOfDayRange)
+    }
+}
+
+impl<const BASE: u8> Default for LightningTimeBaseN<BASE> {
+    fn default() -> Self {
+        Self::new(0, 0, 0, 0, 0)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<const BASE: u8> From<NaiveTime> for LightningTimeBaseN<BASE> {
+    fn from(value: NaiveTime) -> Self {
+        Self::from_nanos_of_day(naive_nanos(&value)).expect("leap seconds to be clamped")
+    }
+}
+
+/// # Panics
+///
+/// Panics if any component is outside of `0..BASE`, see [`LightningTimeBaseN::try_into_naive_time`].
+#[cfg(feature = "chrono")]
+impl<const BASE: u8> From<LightningTimeBaseN<BASE>> for NaiveTime {
+    fn from(value: LightningTimeBaseN<BASE>) -> Self {
+        value
+            .try_into_naive_time()
+            .expect("Lightning Time to never overflow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, LightningTimeBaseN};
+
+    #[test]
+    fn decimal_noon() {
+        let noon = LightningTimeBaseN::<10>::new(5, 0, 0, 0, 0);
+        assert_eq!(LightningTimeBaseN::<10>::SUBCHARGES_PER_DAY, 100_000);
+        assert_eq!(noon.as_subcharges(), 50_000);
+        assert_eq!(noon.nanos_of_day(), 43_200_000_000_000);
+        assert_eq!(
+            LightningTimeBaseN::<10>::from_nanos_of_day(43_200_000_000_000),
+            Ok(noon)
+        );
+        assert_eq!(
+            LightningTimeBaseN::<10>::from_subcharges(12_345),
+            Ok(LightningTimeBaseN::new(1, 2, 3, 4, 5))
+        );
+        assert_eq!(
+            LightningTimeBaseN::<10>::from_subcharges(100_000),
+            Err(Error::OutOfDayRange)
+        );
+        assert_eq!(
+            LightningTimeBaseN::<10>::try_new(0xa, 0, 0, 0, 0),
+            Err(Error::DigitOutOfRange {
+                component: "bolts",
+                value: 0xa
+            })
+        );
+    }
+
+    #[test]
+    fn matches_hex() {
+        let time = LightningTimeBaseN::<16>::from_subcharges(0xf3a8c).unwrap();
+        assert_eq!(time, LightningTimeBaseN::new(0xf, 0x3, 0xa, 0x8, 0xc));
+        assert_eq!(
+            time.nanos_of_day(),
+            crate::LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc).nanos_of_day()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn convert_naive_time() {
+        use chrono::NaiveTime;
+
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        assert_eq!(
+            LightningTimeBaseN::<10>::from(noon),
+            LightningTimeBaseN::new(5, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            LightningTimeBaseN::<12>::from(noon),
+            LightningTimeBaseN::new(6, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            NaiveTime::from(LightningTimeBaseN::<10>::new(5, 0, 0, 0, 0)),
+            noon
+        );
+        assert_eq!(
+            LightningTimeBaseN::<10>::new(0, 0, 0, 0, 10).try_into_naive_time(),
+            Err(Error::DigitOutOfRange {
+                component: "subcharges",
+                value: 10
+            })
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! A C ABI for embedding, built as a static or dynamic library with e.g.
+//! `cargo rustc --release --no-default-features --features ffi --crate-type staticlib`.
+//!
+//! The exported declarations are:
+//!
+//! ```c
+//! typedef struct {
+//!     uint8_t bolts;
+//!     uint8_t zaps;
+//!     uint8_t sparks;
+//!     uint8_t charges;
+//!     uint8_t subcharges;
+//! } FfiLightningTime;
+//!
+//! FfiLightningTime lightning_time_from_millis(uint64_t unix_millis);
+//! intptr_t lightning_time_format(FfiLightningTime time, char *buf, size_t len);
+//! ```
+//!
+//! Every field holds a single hex digit in `0..=15`.
+
+use crate::LightningTime;
+
+/// The C layout of a [`LightningTime`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FfiLightningTime {
+    pub bolts: u8,
+    pub zaps: u8,
+    pub sparks: u8,
+    pub charges: u8,
+    pub subcharges: u8,
+}
+
+impl From<LightningTime> for FfiLightningTime {
+    fn from(time: LightningTime) -> Self {
+        let [bolts, zaps, sparks, charges, subcharges] = time.components();
+        Self {
+            bolts,
+            zaps,
+            sparks,
+            charges,
+            subcharges,
+        }
+    }
+}
+
+impl TryFrom<FfiLightningTime> for LightningTime {
+    type Error = crate::Error;
+
+    fn try_from(time: FfiLightningTime) -> Result<Self, Self::Error> {
+        LightningTime::try_new(
+            time.bolts,
+            time.zaps,
+            time.sparks,
+            time.charges,
+            time.subcharges,
+        )
+    }
+}
+
+/// Converts milliseconds since the Unix epoch to the UTC time of day. Only the time of day is
+/// used, so a millisecond-of-day value works too.
+#[no_mangle]
+pub extern "C" fn lightning_time_from_millis(unix_millis: u64) -> FfiLightningTime {
+    let millis = unix_millis % LightningTime::MILLIS_PER_DAY as u64;
+    LightningTime::from_elapsed_nanos(millis as i128 * 1_000_000).into()
+}
+
+/// Formats `time` like `8~0~0|00` into `buf` followed by a NUL terminator.
+///
+/// Returns the length of the string without the terminator, or `-1` if a field is out of range.
+/// Nothing is written if `buf` is null or `len` isn't greater than the returned length, so a
+/// null `buf` can be used to query the length. Nine bytes always suffice.
+///
+/// # Safety
+///
+/// `buf` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn lightning_time_format(
+    time: FfiLightningTime,
+    buf: *mut core::ffi::c_char,
+    len: usize,
+) -> isize {
+    let Ok(time) = LightningTime::try_from(time) else {
+        return -1;
+    };
+
+    let mut out = [0u8; 9];
+    let Ok(written) = time.write_to(&mut out) else {
+        return -1;
+    };
+
+    if !buf.is_null() && len > written {
+        // SAFETY: the caller guarantees `buf` is valid for `len` bytes, which covers the string
+        // and its terminator
+        unsafe {
+            core::ptr::copy_nonoverlapping(out.as_ptr(), buf.cast(), written);
+            *buf.add(written) = 0;
+        }
+    }
+    written as isize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_millis() {
+        let noon = lightning_time_from_millis(12 * 60 * 60 * 1000);
+        assert_eq!(noon, LightningTime::new(8, 0, 0, 0).into());
+        // 2024-01-01T12:00:00Z
+        assert_eq!(lightning_time_from_millis(1_704_110_400_000), noon);
+    }
+
+    #[test]
+    fn format() {
+        let time = lightning_time_from_millis(12 * 60 * 60 * 1000);
+        let mut buf = [0x7f as core::ffi::c_char; 16];
+        let len = unsafe { lightning_time_format(time, buf.as_mut_ptr(), buf.len()) };
+        assert_eq!(len, 8);
+        let written = unsafe { core::ffi::CStr::from_ptr(buf.as_ptr()) };
+        assert_eq!(written.to_bytes(), b"8~0~0|00");
+
+        assert_eq!(
+            unsafe { lightning_time_format(time, core::ptr::null_mut(), 0) },
+            8
+        );
+    }
+
+    #[test]
+    fn format_short_buffer() {
+        let time = lightning_time_from_millis(0);
+        let mut buf = [0x7f as core::ffi::c_char; 8];
+        assert_eq!(
+            unsafe { lightning_time_format(time, buf.as_mut_ptr(), buf.len()) },
+            8
+        );
+        assert!(buf.iter().all(|&b| b == 0x7f));
+    }
+
+    #[test]
+    fn format_invalid() {
+        let mut time = lightning_time_from_millis(0);
+        time.bolts = 16;
+        assert_eq!(
+            unsafe { lightning_time_format(time, core::ptr::null_mut(), 0) },
+            -1
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{Error, LightningTime};
+
+/// The separators used to format and parse Lightning Time, e.g. `:` and `·` for `8:0:0·00`. The
+/// default matches [`Display`](core::fmt::Display) and [`FromStr`](core::str::FromStr).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightningFormat {
+    /// Separates bolts, zaps, and sparks.
+    pub group_separator: char,
+    /// Separates sparks from charges.
+    pub charge_separator: char,
+}
+
+impl Default for LightningFormat {
+    fn default() -> Self {
+        Self {
+            group_separator: '~',
+            charge_separator: '|',
+        }
+    }
+}
+
+struct Cursor<'a> {
+    s: &'a str,
+    position: usize,
+}
+
+impl Cursor<'_> {
+    fn is_done(&self) -> bool {
+        self.position == self.s.len()
+    }
+
+    fn invalid(&self) -> Error {
+        Error::InvalidFormat {
+            position: self.position,
+        }
+    }
+
+    fn next_if(&mut self, f: impl FnOnce(char) -> Option<u8>) -> Result<u8, Error> {
+        let c = self.s[self.position..].chars().next();
+        let value = c.and_then(f).ok_or(self.invalid())?;
+        self.position += c.map_or(0, char::len_utf8);
+        Ok(value)
+    }
+
+    fn digit(&mut self) -> Result<u8, Error> {
+        self.next_if(|c| c.to_digit(16).map(|d| d as u8))
+    }
+
+    fn separator(&mut self, separator: char) -> Result<(), Error> {
+        self.next_if(|c| (c == separator).then_some(0)).map(|_| ())
+    }
+}
+
+impl LightningFormat {
+    /// Parses `bolt~zap~spark` followed by an optional `|charge` and then an optional subcharge
+    /// digit, with this format's separators in place of `~` and `|`.
+    pub fn parse(&self, s: &str) -> Result<LightningTime, Error> {
+        let mut cursor = Cursor { s, position: 0 };
+
+        let bolts = cursor.digit()?;
+        cursor.separator(self.group_separator)?;
+        let zaps = cursor.digit()?;
+        cursor.separator(self.group_separator)?;
+        let sparks = cursor.digit()?;
+        let (charges, subcharges) = if cursor.is_done() {
+            (0, 0)
+        } else {
+            cursor.separator(self.charge_separator)?;
+            let charges = cursor.digit()?;
+            if cursor.is_done() {
+                (charges, 0)
+            } else {
+                (charges, cursor.digit()?)
+            }
+        };
+        if !cursor.is_done() {
+            return Err(cursor.invalid());
+        }
+
+        Ok(LightningTime::from_parts(
+            bolts, zaps, sparks, charges, subcharges,
+        ))
+    }
+}
+
+impl LightningTime {
+    /// Like [`Display`](core::fmt::Display), but with the separators from `format`.
+    #[cfg(feature = "std")]
+    pub fn format_with(&self, format: &LightningFormat) -> String {
+        let LightningFormat {
+            group_separator: group,
+            charge_separator: charge,
+        } = format;
+        format!(
+            "{:x}{group}{:x}{group}{:x}{charge}{:x}{:x}",
+            self.bolts, self.zaps, self.sparks, self.charges, self.subcharges
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, LightningFormat, LightningTime};
+
+    #[test]
+    fn default_format() {
+        use core::str::FromStr;
+
+        let format = LightningFormat::default();
+        for s in [
+            "f~3~a|8c",
+            "8~0~0",
+            "8~0~0|3",
+            "g~0~0",
+            "8~0~0|",
+            "8~0~0|000",
+        ] {
+            assert_eq!(format.parse(s), LightningTime::from_str(s));
+        }
+    }
+
+    #[test]
+    fn custom_separators() {
+        let format = LightningFormat {
+            group_separator: ':',
+            charge_separator: '·',
+        };
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(format.parse("f:3:a·8c"), Ok(time));
+        assert_eq!(format.parse("8:0:0"), Ok(LightningTime::new(8, 0, 0, 0)));
+        assert_eq!(
+            format.parse("8~0~0"),
+            Err(Error::InvalidFormat { position: 1 })
+        );
+        assert_eq!(
+            format.parse("8:0:0·8c!"),
+            Err(Error::InvalidFormat { position: 9 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn format_with() {
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(
+            time.format_with(&LightningFormat::default()),
+            time.to_string()
+        );
+
+        let format = LightningFormat {
+            group_separator: ':',
+            charge_separator: '·',
+        };
+        assert_eq!(time.format_with(&format), "f:3:a·8c");
+        assert_eq!(format.parse(&time.format_with(&format)), Ok(time));
+    }
+}
@@ -1,31 +1,191 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use core::str::FromStr;
 #[cfg(feature = "std")]
-use std::{str::FromStr, sync::OnceLock};
+use std::sync::OnceLock;
 
-use chrono::{NaiveTime, Timelike};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Duration, NaiveTime, TimeZone, Timelike};
+use palette::FromColor;
 #[cfg(feature = "std")]
-use regex::Regex;
+use regex::{Captures, Regex};
 use thiserror_no_std::Error;
 
+mod base_n;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod format;
+mod offset;
+mod range;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use base_n::LightningTimeBaseN;
+pub use format::LightningFormat;
+pub use offset::LightningOffset;
+pub use range::LightningRange;
+#[cfg(feature = "wasm")]
+pub use wasm::WasmLightningTime;
+
+/// The base colors and channels used to turn a time into colors. Fields may be added in the
+/// future, so build one with [`LightningTimeColorConfig::builder`] or start from
+/// [`LightningTimeColorConfig::DEFAULT`] or a [preset](LightningTimeColorConfig::preset).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct LightningTimeColorConfig {
     pub bolt: LightningBaseColors,
     pub zap: LightningBaseColors,
     pub spark: LightningBaseColors,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub channels: LightningChannels,
 }
 
 impl Default for LightningTimeColorConfig {
     fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl LightningTimeColorConfig {
+    /// The default theme, usable in const contexts unlike [`Default::default`].
+    pub const DEFAULT: Self = Self {
+        bolt: LightningBaseColors(161, 0),
+        zap: LightningBaseColors(50, 214),
+        spark: LightningBaseColors(246, 133),
+        channels: LightningChannels::DEFAULT,
+    };
+
+    /// Starts building a config from the default theme.
+    pub fn builder() -> LightningTimeColorConfigBuilder {
+        LightningTimeColorConfigBuilder::default()
+    }
+
+    /// The names accepted by [`LightningTimeColorConfig::preset`].
+    pub const PRESETS: [&'static str; 3] = ["default", "solarized", "monochrome"];
+
+    /// Looks up a preset theme by name, see [`LightningTimeColorConfig::PRESETS`].
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            "solarized" => Some(Self::solarized()),
+            "monochrome" => Some(Self::monochrome()),
+            _ => None,
+        }
+    }
+
+    /// Colors based on the Solarized accent colors.
+    pub fn solarized() -> Self {
+        Self {
+            bolt: LightningBaseColors(137, 0),
+            zap: LightningBaseColors(42, 152),
+            spark: LightningBaseColors(211, 54),
+            channels: Default::default(),
+        }
+    }
+
+    /// Shades of green, with every dynamic value in the green channel.
+    pub fn monochrome() -> Self {
         Self {
-            bolt: LightningBaseColors(161, 0),
-            zap: LightningBaseColors(50, 214),
-            spark: LightningBaseColors(246, 133),
+            bolt: LightningBaseColors(0, 0),
+            zap: LightningBaseColors(0, 0),
+            spark: LightningBaseColors(0, 0),
+            channels: LightningChannels {
+                bolt: LightningChannel::Green,
+                zap: LightningChannel::Green,
+                spark: LightningChannel::Green,
+            },
         }
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LightningTimeColorConfigBuilder {
+    config: LightningTimeColorConfig,
+}
+
+impl LightningTimeColorConfigBuilder {
+    pub fn bolt(mut self, first: u8, second: u8) -> Self {
+        self.config.bolt = LightningBaseColors(first, second);
+        self
+    }
+
+    pub fn zap(mut self, first: u8, second: u8) -> Self {
+        self.config.zap = LightningBaseColors(first, second);
+        self
+    }
+
+    pub fn spark(mut self, first: u8, second: u8) -> Self {
+        self.config.spark = LightningBaseColors(first, second);
+        self
+    }
+
+    pub fn channels(mut self, channels: LightningChannels) -> Self {
+        self.config.channels = channels;
+        self
+    }
+
+    pub fn build(self) -> LightningTimeColorConfig {
+        self.config
+    }
+}
+
+/// The channel of each color that holds its dynamic value, the base colors fill the remaining
+/// channels in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightningChannels {
+    pub bolt: LightningChannel,
+    pub zap: LightningChannel,
+    pub spark: LightningChannel,
+}
+
+impl Default for LightningChannels {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl LightningChannels {
+    /// Bolt, zap, and spark in the red, green, and blue channels respectively.
+    pub const DEFAULT: Self = Self {
+        bolt: LightningChannel::Red,
+        zap: LightningChannel::Green,
+        spark: LightningChannel::Blue,
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum LightningChannel {
+    Red,
+    Green,
+    Blue,
+}
+
+impl LightningChannel {
+    #[inline]
+    fn compose<T>(self, dynamic: T, base: (T, T)) -> palette::Srgb<T> {
+        match self {
+            Self::Red => palette::Srgb::new(dynamic, base.0, base.1),
+            Self::Green => palette::Srgb::new(base.0, dynamic, base.1),
+            Self::Blue => palette::Srgb::new(base.0, base.1, dynamic),
+        }
+    }
+
+    fn decompose<T>(self, color: palette::Srgb<T>) -> (T, (T, T)) {
+        let (red, green, blue) = color.into_components();
+        match self {
+            Self::Red => (red, (green, blue)),
+            Self::Green => (green, (red, blue)),
+            Self::Blue => (blue, (red, green)),
+        }
+    }
+}
+
+/// Fields are ordered by significance, so comparisons of in range times are chronological.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct LightningTime {
     pub bolts: u8,
     pub zaps: u8,
@@ -35,6 +195,7 @@ pub struct LightningTime {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightningBaseColors(pub u8, pub u8);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -44,219 +205,2937 @@ pub struct LightningTimeColors {
     pub spark: palette::Srgb<u8>,
 }
 
+/// A single level of Lightning Time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LightningStep {
+    Bolt,
+    Zap,
+    Spark,
+    Charge,
+    Subcharge,
+}
+
+impl LightningStep {
+    /// The number of subcharges in one unit of this level.
+    pub const fn subcharges(self) -> u32 {
+        match self {
+            Self::Bolt => 0x10000,
+            Self::Zap => 0x1000,
+            Self::Spark => 0x100,
+            Self::Charge => 0x10,
+            Self::Subcharge => 0x1,
+        }
+    }
+}
+
+impl LightningTimeColors {
+    /// Formats the bolt, zap, and spark colors as `#rrggbb` strings.
+    #[cfg(feature = "std")]
+    pub fn to_hex_strings(&self) -> [String; 3] {
+        [self.bolt, self.zap, self.spark].map(|c| format!("#{c:x}"))
+    }
+
+    /// Recovers the time these colors were computed from, without subcharges which aren't encoded.
+    /// Fails if the base colors don't match the config or the colors disagree on shared nibbles.
+    pub fn try_to_lightning_time(
+        &self,
+        config: &LightningTimeColorConfig,
+    ) -> Result<LightningTime, Error> {
+        let channels = &config.channels;
+        let unpack = |color, channel: LightningChannel, base: LightningBaseColors| {
+            let (dynamic, found) = channel.decompose(color);
+            if found != (base.0, base.1) {
+                return Err(Error::ColorMismatch);
+            }
+            Ok((dynamic >> 4, dynamic & 0xf))
+        };
+
+        let (bolts, zaps) = unpack(self.bolt, channels.bolt, config.bolt)?;
+        let (zaps_low, sparks) = unpack(self.zap, channels.zap, config.zap)?;
+        let (sparks_low, charges) = unpack(self.spark, channels.spark, config.spark)?;
+        if zaps != zaps_low || sparks != sparks_low {
+            return Err(Error::ColorMismatch);
+        }
+
+        Ok(LightningTime::new(bolts, zaps, sparks, charges))
+    }
+
+    /// Recovers the complete time from colors made by [`LightningTime::colors_full_precision`].
+    /// Fails if the remaining base colors don't match the config or the colors disagree on shared
+    /// nibbles.
+    pub fn try_from_full_precision(
+        &self,
+        config: &LightningTimeColorConfig,
+    ) -> Result<LightningTime, Error> {
+        let (_, (charges_subcharges, _)) = config.channels.spark.decompose(self.spark);
+        let time = self.try_to_lightning_time(&LightningTimeColorConfig {
+            spark: LightningBaseColors(charges_subcharges, config.spark.1),
+            ..*config
+        })?;
+        if time.charges != charges_subcharges >> 4 {
+            return Err(Error::ColorMismatch);
+        }
+
+        Ok(LightningTime {
+            subcharges: charges_subcharges & 0xf,
+            ..time
+        })
+    }
+
+    /// Converts the bolt, zap, and spark colors to HSL.
+    pub fn to_hsl(&self) -> [palette::Hsl; 3] {
+        [self.bolt, self.zap, self.spark].map(|c| palette::Hsl::from_color(c.into_format()))
+    }
+
+    /// A single color for the whole time, the mean of the bolt, zap, and spark colors in each sRGB
+    /// component, rounded to the nearest value.
+    pub fn blended(&self) -> palette::Srgb<u8> {
+        let mean = |a: u8, b: u8, c: u8| ((a as u16 + b as u16 + c as u16 + 1) / 3) as u8;
+        let [bolt, zap, spark] = [self.bolt, self.zap, self.spark];
+
+        palette::Srgb::new(
+            mean(bolt.red, zap.red, spark.red),
+            mean(bolt.green, zap.green, spark.green),
+            mean(bolt.blue, zap.blue, spark.blue),
+        )
+    }
+
+    /// Interpolates each color from `a` at `t = 0` to `b` at `t = 1`, mixing in linear RGB so
+    /// crossfades don't dip through darker colors. `t` is clamped to `[0, 1]`, and NaN is treated
+    /// as `0.0`.
+    pub fn lerp(a: Self, b: Self, t: f64) -> Self {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let mix = |a: palette::Srgb<u8>, b: palette::Srgb<u8>| {
+            let a = a.into_format::<f64>().into_linear();
+            let b = b.into_format::<f64>().into_linear();
+            palette::Srgb::from_linear(palette::Mix::mix(a, b, t))
+        };
+
+        Self {
+            bolt: mix(a.bolt, b.bolt),
+            zap: mix(a.zap, b.zap),
+            spark: mix(a.spark, b.spark),
+        }
+    }
+
+    /// Converts the bolt, zap, and spark colors to HSV.
+    pub fn to_hsv(&self) -> [palette::Hsv; 3] {
+        [self.bolt, self.zap, self.spark].map(|c| palette::Hsv::from_color(c.into_format()))
+    }
+
+    /// Converts the bolt, zap, and spark colors to OKLCH, e.g. for CSS `oklch()` colors.
+    pub fn to_oklch(&self) -> [palette::Oklch; 3] {
+        [self.bolt, self.zap, self.spark].map(|c| palette::Oklch::from_color(c.into_format()))
+    }
+}
+
 impl LightningTime {
-    pub fn new(bolts: u8, zaps: u8, sparks: u8, charges: u8) -> Self {
+    /// The start of the day, `0~0~0|00`.
+    pub const MIDNIGHT: Self = Self::new(0, 0, 0, 0);
+    /// The middle of the day, `8~0~0|00`.
+    pub const NOON: Self = Self::new(8, 0, 0, 0);
+    /// The number of subcharges in a day, 16^5.
+    pub const SUBCHARGES_PER_DAY: u32 = 1048576;
+    /// The length of an Earth day in milliseconds, used by every conversion that doesn't take a
+    /// custom day length.
+    pub const MILLIS_PER_DAY: u32 = 86_400_000;
+    /// The length of a subcharge in milliseconds.
+    ///
+    /// ```
+    /// # use lightning_time::LightningTime;
+    /// let millis = 12. * 60. * 60. * 1000.;
+    /// let subcharges = (millis / LightningTime::MILLIS_PER_SUBCHARGE) as u32;
+    /// assert_eq!(LightningTime::from_subcharges(subcharges).unwrap(), LightningTime::new(8, 0, 0, 0));
+    /// ```
+    pub const MILLIS_PER_SUBCHARGE: f64 = 86_400_000.0 / Self::SUBCHARGES_PER_DAY as f64;
+    /// The length of a subcharge in nanoseconds. Not a whole number, so integer conversions use
+    /// the length of a day instead.
+    pub const NANOS_PER_SUBCHARGE: f64 = NANOS_PER_DAY as f64 / Self::SUBCHARGES_PER_DAY as f64;
+    /// The number of units each level divides into, e.g. zaps per bolt.
+    pub const UNITS_PER_LEVEL: u8 = 16;
+
+    /// Creates a new Lightning Time without validating components, see [`LightningTime::try_new`].
+    pub const fn new(bolts: u8, zaps: u8, sparks: u8, charges: u8) -> Self {
         Self {
             bolts,
             zaps,
             sparks,
             charges,
-            ..Default::default()
+            subcharges: 0,
+        }
+    }
+
+    /// Creates a new Lightning Time including subcharges without validating components.
+    pub const fn from_parts(bolts: u8, zaps: u8, sparks: u8, charges: u8, subcharges: u8) -> Self {
+        Self {
+            bolts,
+            zaps,
+            sparks,
+            charges,
+            subcharges,
         }
     }
 
+    /// Creates a new Lightning Time, failing if any component is not a single hex digit.
+    pub fn try_new(
+        bolts: u8,
+        zaps: u8,
+        sparks: u8,
+        charges: u8,
+        subcharges: u8,
+    ) -> Result<Self, Error> {
+        let lightning = Self {
+            bolts,
+            zaps,
+            sparks,
+            charges,
+            subcharges,
+        };
+        lightning.validate()?;
+
+        Ok(lightning)
+    }
+
+    /// Computes the colors for this time. Each dynamic channel packs two components as nibbles, so
+    /// only the low nibble of any out of range component is used, see [`LightningTime::checked_colors`].
+    #[inline]
     pub fn colors(&self, config: &LightningTimeColorConfig) -> LightningTimeColors {
+        let pack = |high: u8, low: u8| ((high & 0xf) << 4) | (low & 0xf);
+
+        let channels = &config.channels;
+
         LightningTimeColors {
-            bolt: palette::Srgb::new(self.bolts * 16 + self.zaps, config.bolt.0, config.bolt.1),
-            zap: palette::Srgb::new(config.zap.0, self.zaps * 16 + self.sparks, config.zap.1),
-            spark: palette::Srgb::new(
-                config.spark.0,
+            bolt: channels
+                .bolt
+                .compose(pack(self.bolts, self.zaps), (config.bolt.0, config.bolt.1)),
+            zap: channels
+                .zap
+                .compose(pack(self.zaps, self.sparks), (config.zap.0, config.zap.1)),
+            spark: channels.spark.compose(
+                pack(self.sparks, self.charges),
+                (config.spark.0, config.spark.1),
+            ),
+        }
+    }
+
+    /// Like [`LightningTime::colors`], but the first base color byte of the spark color is replaced
+    /// with `0xCS`, the charges then subcharges nibbles, so the colors encode the complete time. For
+    /// the default blue spark channel that is the red byte. Recover the time with
+    /// [`LightningTimeColors::try_from_full_precision`].
+    pub fn colors_full_precision(&self, config: &LightningTimeColorConfig) -> LightningTimeColors {
+        self.colors(&LightningTimeColorConfig {
+            spark: LightningBaseColors(
+                ((self.charges & 0xf) << 4) | (self.subcharges & 0xf),
                 config.spark.1,
-                self.sparks * 16 + self.charges,
             ),
+            ..*config
+        })
+    }
+
+    /// Computes smooth colors for this time, where `fraction` is the position within the current
+    /// subcharge in `0.0..1.0`. Out of range values are clamped to `0.0..=1.0`, so they never reach
+    /// past the next subcharge, and NaN is treated as `0.0`.
+    ///
+    /// Each dynamic channel is the continuous elapsed subcharge count divided down to the level of
+    /// its pair of nibbles, i.e. `(subcharges + fraction) / 16^k mod 256` where `k` is 3 for bolts, 2
+    /// for zaps, and 1 for sparks. Flooring that value gives the channel used by
+    /// [`LightningTime::colors`], and all channels are scaled to `0.0..=1.0`.
+    pub fn colors_interpolated(
+        &self,
+        config: &LightningTimeColorConfig,
+        fraction: f64,
+    ) -> [palette::Srgb; 3] {
+        let fraction = if fraction.is_nan() {
+            0.
+        } else {
+            fraction.clamp(0., 1.)
+        };
+        let elapsed = (self.as_subcharges() % Self::SUBCHARGES_PER_DAY) as f64 + fraction;
+        let channel = |k: i32| {
+            let value = elapsed / libm::pow(16., k as f64) % 256.;
+            (value / 255.).min(1.) as f32
+        };
+        let base = |base: LightningBaseColors| (base.0 as f32 / 255., base.1 as f32 / 255.);
+        let channels = &config.channels;
+
+        [
+            channels.bolt.compose(channel(3), base(config.bolt)),
+            channels.zap.compose(channel(2), base(config.zap)),
+            channels.spark.compose(channel(1), base(config.spark)),
+        ]
+    }
+
+    /// Computes the colors for this time, returning `None` if any component is outside of `0..16`.
+    pub fn checked_colors(&self, config: &LightningTimeColorConfig) -> Option<LightningTimeColors> {
+        self.validate().ok().map(|_| self.colors(config))
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        for (component, value) in [
+            ("bolts", self.bolts),
+            ("zaps", self.zaps),
+            ("sparks", self.sparks),
+            ("charges", self.charges),
+            ("subcharges", self.subcharges),
+        ] {
+            check_digit(component, value)?;
         }
+
+        Ok(())
     }
 
-    #[cfg(feature = "std")]
-    pub fn to_stripped_string(&self) -> String {
-        format!("{:x}~{:x}~{:x}", self.bolts, self.zaps, self.sparks)
+    /// The components from bolts down to subcharges.
+    pub const fn components(&self) -> [u8; 5] {
+        [
+            self.bolts,
+            self.zaps,
+            self.sparks,
+            self.charges,
+            self.subcharges,
+        ]
     }
 
-    pub fn now() -> Self {
-        Self::from(chrono::offset::Local::now().naive_local().time())
+    /// Creates a Lightning Time from components ordered like [`LightningTime::components`], failing
+    /// if any component is not a single hex digit.
+    pub fn from_components(components: [u8; 5]) -> Result<Self, Error> {
+        let [bolts, zaps, sparks, charges, subcharges] = components;
+        Self::try_new(bolts, zaps, sparks, charges, subcharges)
     }
-}
 
-const MILLIS_PER_SUBCHARGE: f64 = 86_400_000.0 / 1048576.0; // Div by 16^5
+    /// The coarsest level whose component differs from `prev`, or `None` if the times are equal.
+    pub fn highest_changed_level(&self, prev: &Self) -> Option<LightningStep> {
+        [
+            LightningStep::Bolt,
+            LightningStep::Zap,
+            LightningStep::Spark,
+            LightningStep::Charge,
+            LightningStep::Subcharge,
+        ]
+        .into_iter()
+        .zip(self.components().into_iter().zip(prev.components()))
+        .find_map(|(level, (current, prev))| (current != prev).then_some(level))
+    }
 
-impl From<NaiveTime> for LightningTime {
-    fn from(value: NaiveTime) -> Self {
-        let millis = 1_000. * 60. * 60. * value.hour() as f64
-            + 1_000. * 60. * value.minute() as f64
-            + 1_000. * value.second() as f64
-            + value.nanosecond() as f64 / 1.0e6;
+    /// Returns a copy with `bolts` replaced, failing if it is not a single hex digit.
+    pub fn with_bolts(self, bolts: u8) -> Result<Self, Error> {
+        Ok(Self {
+            bolts: check_digit("bolts", bolts)?,
+            ..self
+        })
+    }
 
-        let total_subcharges = millis / MILLIS_PER_SUBCHARGE;
-        let total_charges = total_subcharges / 16.;
-        let total_sparks = total_charges / 16.;
-        let total_zaps = total_sparks / 16.;
-        let total_bolts = total_zaps / 16.;
+    /// Returns a copy with `zaps` replaced, failing if it is not a single hex digit.
+    pub fn with_zaps(self, zaps: u8) -> Result<Self, Error> {
+        Ok(Self {
+            zaps: check_digit("zaps", zaps)?,
+            ..self
+        })
+    }
 
-        #[cfg(feature = "std")]
-        {
-            LightningTime {
-                bolts: (total_bolts.floor() % 16.) as u8,
-                sparks: (total_sparks.floor() % 16.) as u8,
-                zaps: (total_zaps.floor() % 16.) as u8,
-                charges: (total_charges.floor() % 16.) as u8,
-                subcharges: (total_subcharges.floor() % 16.) as u8,
-            }
-        }
+    /// Returns a copy with `sparks` replaced, failing if it is not a single hex digit.
+    pub fn with_sparks(self, sparks: u8) -> Result<Self, Error> {
+        Ok(Self {
+            sparks: check_digit("sparks", sparks)?,
+            ..self
+        })
+    }
 
-        #[cfg(not(feature = "std"))]
-        {
-            use libm::floor;
-            LightningTime {
-                bolts: (floor(total_bolts) % 16.) as u8,
-                sparks: (floor(total_sparks) % 16.) as u8,
-                zaps: (floor(total_zaps) % 16.) as u8,
-                charges: (floor(total_charges) % 16.) as u8,
-                subcharges: (floor(total_subcharges) % 16.) as u8,
-            }
+    /// Returns a copy with `charges` replaced, failing if it is not a single hex digit.
+    pub fn with_charges(self, charges: u8) -> Result<Self, Error> {
+        Ok(Self {
+            charges: check_digit("charges", charges)?,
+            ..self
+        })
+    }
+
+    /// Returns a copy with `subcharges` replaced, failing if it is not a single hex digit.
+    pub fn with_subcharges(self, subcharges: u8) -> Result<Self, Error> {
+        Ok(Self {
+            subcharges: check_digit("subcharges", subcharges)?,
+            ..self
+        })
+    }
+
+    /// Packs the components into the low 20 bits as `0x000BZSCS`, one nibble per level from bolts
+    /// down to subcharges. Only the low nibble of any out of range component is used, so for in
+    /// range times this matches [`LightningTime::as_subcharges`].
+    pub fn pack(&self) -> u32 {
+        [self.zaps, self.sparks, self.charges, self.subcharges]
+            .into_iter()
+            .fold((self.bolts & 0xf) as u32, |packed, level| {
+                packed << 4 | (level & 0xf) as u32
+            })
+    }
+
+    /// Unpacks the layout of [`LightningTime::pack`], failing if any bits above the low 20 are set.
+    pub fn unpack(n: u32) -> Result<Self, Error> {
+        if n >> 20 != 0 {
+            return Err(Error::OutOfDayRange);
         }
+
+        Ok(Self::from_elapsed_subcharges(n))
     }
-}
 
-#[cfg(feature = "std")]
-static RE: OnceLock<Regex> = OnceLock::new();
+    /// The layout of [`LightningTime::pack`] as three big-endian bytes, `[0x0B, 0xZS, 0xCS]`.
+    pub fn to_bytes(&self) -> [u8; 3] {
+        let [_, bytes @ ..] = self.pack().to_be_bytes();
+        bytes
+    }
 
-#[cfg(feature = "std")]
-impl FromStr for LightningTime {
-    type Err = Error;
+    /// Reads the layout of [`LightningTime::to_bytes`], failing if the high nibble of the first byte
+    /// is set.
+    pub fn from_bytes(bytes: [u8; 3]) -> Result<Self, Error> {
+        let [bolts, zaps_sparks, charges_subcharges] = bytes;
+        Self::unpack(u32::from_be_bytes([
+            0,
+            bolts,
+            zaps_sparks,
+            charges_subcharges,
+        ]))
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let re = RE.get_or_init(|| {
-            Regex::new(r"(?P<bolt>[[:xdigit:]])~(?P<spark>[[:xdigit:]])~(?P<zap>[[:xdigit:]])(?:\|(?P<charge>[[:xdigit:]])(?P<subcharge>[[:xdigit:]])?)?").unwrap()
-        });
+    fn angle(&self, level: LightningStep) -> f64 {
+        let turn = level.subcharges() * 16;
+        (self.as_subcharges() % turn) as f64 / turn as f64 * 360.
+    }
 
-        let caps = re.captures(s);
-        match caps {
-            Some(caps) => {
-                if caps.len() < 3 {
-                    return Err(Error::InvalidConversion);
-                }
-                Ok(LightningTime {
-                    bolts: u8::from_str_radix(caps.name("bolt").unwrap().as_str(), 16).unwrap(),
-                    zaps: u8::from_str_radix(caps.name("zap").unwrap().as_str(), 16).unwrap(),
-                    sparks: u8::from_str_radix(caps.name("spark").unwrap().as_str(), 16).unwrap(),
-                    charges: caps
-                        .name("charge")
-                        .map(|c| u8::from_str_radix(c.as_str(), 16).unwrap())
-                        .unwrap_or(0),
-                    subcharges: caps
-                        .name("subcharge")
-                        .map(|c| u8::from_str_radix(c.as_str(), 16).unwrap())
-                        .unwrap_or(0),
-                })
-            }
-            None => Err(Error::InvalidConversion),
-        }
+    /// The angle in degrees of the bolt hand, measured clockwise from 0° at the top. Includes the
+    /// fraction contributed by lower levels.
+    pub fn bolt_angle(&self) -> f64 {
+        self.angle(LightningStep::Bolt)
     }
-}
 
-impl core::fmt::Display for LightningTime {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_fmt(format_args!(
-            "{:x}~{:x}~{:x}|{:x}{:x}",
-            self.bolts, self.zaps, self.sparks, self.charges, self.subcharges
-        ))
+    /// The angle of the zap hand, see [`LightningTime::bolt_angle`] for the convention.
+    pub fn zap_angle(&self) -> f64 {
+        self.angle(LightningStep::Zap)
     }
-}
 
-#[derive(Debug, Clone, Copy, Error)]
-pub enum Error {
-    #[error("Invalid conversion")]
-    InvalidConversion,
-}
+    /// The angle of the spark hand, see [`LightningTime::bolt_angle`] for the convention.
+    pub fn spark_angle(&self) -> f64 {
+        self.angle(LightningStep::Spark)
+    }
 
-impl From<LightningTime> for NaiveTime {
-    fn from(value: LightningTime) -> Self {
-        let elapsed: usize =
-            (((value.bolts as usize * 16 + value.zaps as usize) * 16 + value.sparks as usize) * 16
-                + value.charges as usize)
-                * 16
-                + value.subcharges as usize;
+    /// The angle of the charge hand, see [`LightningTime::bolt_angle`] for the convention.
+    pub fn charge_angle(&self) -> f64 {
+        self.angle(LightningStep::Charge)
+    }
 
-        let millis = elapsed as f64 * MILLIS_PER_SUBCHARGE;
+    /// The angle of the subcharge hand, see [`LightningTime::bolt_angle`] for the convention.
+    pub fn subcharge_angle(&self) -> f64 {
+        self.angle(LightningStep::Subcharge)
+    }
 
-        let seconds = millis / 1000.;
-        let leftover_millis = millis % 1000.;
+    /// Carries components above 15 into the next level up, like positional notation, wrapping the
+    /// result around at midnight.
+    pub fn normalized(&self) -> LightningTime {
+        Self::from_elapsed_subcharges(self.as_subcharges())
+    }
 
-        NaiveTime::from_num_seconds_from_midnight_opt(
-            seconds as u32,
-            (leftover_millis * 1.0e6) as u32,
-        )
-        .expect("Lightning Time to never overflow")
+    /// Clamps each component to `0..=15` without carrying, unlike [`LightningTime::normalized`].
+    pub fn clamped(&self) -> LightningTime {
+        Self {
+            bolts: self.bolts.min(15),
+            zaps: self.zaps.min(15),
+            sparks: self.sparks.min(15),
+            charges: self.charges.min(15),
+            subcharges: self.subcharges.min(15),
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use chrono::{NaiveTime, Timelike};
-    use palette::Srgb;
+    /// Parses like [`FromStr`], but requires the full canonical form with both charge digits, e.g.
+    /// `8~0~0|00` rather than `8~0~0` or `8~0~0|8`.
+    pub fn from_str_strict(s: &str) -> Result<Self, Error> {
+        let time = Self::from_str(s)?;
+        // Every accepted character is ASCII, so only the full form is eight bytes long
+        if s.len() < 8 {
+            return Err(Error::InvalidFormat { position: s.len() });
+        }
 
-    use crate::{LightningTime, LightningTimeColors};
+        Ok(time)
+    }
 
-    #[test]
-    fn convert_to_lightning() {
-        let real = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
-        let lightning = LightningTime::from(real);
-        assert_eq!(
-            lightning,
-            LightningTime {
-                bolts: 0x8,
-                ..Default::default()
+    /// Parses like [`FromStr`], but allows up to two digits in each of the bolt, zap, and spark
+    /// groups and then carries them with [`LightningTime::normalized`], so `8~0~1f` is `8~1~f|00`.
+    /// The charge section keeps its usual meaning, so `8~0~0|1f` is charge 1 and subcharge f.
+    pub fn parse_normalized(s: &str) -> Result<Self, Error> {
+        // Every group is a subslice of `s`, so its position is its offset from the start
+        let position = |group: &str| group.as_ptr() as usize - s.as_ptr() as usize;
+        let group = |group: &str| {
+            if (1..=2).contains(&group.len()) && group.bytes().all(|b| b.is_ascii_hexdigit()) {
+                Ok(u8::from_str_radix(group, 16).unwrap())
+            } else {
+                Err(Error::InvalidFormat {
+                    position: position(group),
+                })
             }
-        );
+        };
 
-        #[cfg(feature = "std")]
-        {
-            assert_eq!(lightning.to_string(), "8~0~0|00");
-            assert_eq!(lightning.to_stripped_string(), "8~0~0");
+        let (core, charge) = match s.split_once('|') {
+            Some((core, charge)) => (core, Some(charge)),
+            None => (s, None),
+        };
+        let mut groups = core.split('~');
+        let (Some(bolts), Some(zaps), Some(sparks)) = (groups.next(), groups.next(), groups.next())
+        else {
+            return Err(Error::InvalidFormat {
+                position: core.len(),
+            });
+        };
+        if let Some(extra) = groups.next() {
+            return Err(Error::InvalidFormat {
+                position: position(extra) - 1,
+            });
         }
-        assert_eq!(
-            lightning.colors(&Default::default()),
-            LightningTimeColors {
-                bolt: Srgb::new(0x80, 0xa1, 0x00),
-                zap: Srgb::new(0x32, 0x00, 0xd6),
-                spark: Srgb::new(0xf6, 0x85, 0x00),
+        let (charges, subcharges) = match charge {
+            None => (0, 0),
+            Some(charge) if charge.len() == 1 => (group(charge)?, 0),
+            Some(charge) => {
+                let charge = group(charge)?;
+                (charge >> 4, charge & 0xf)
             }
-        );
-    }
+        };
 
-    #[test]
-    #[cfg(feature = "std")]
-    fn parse() {
-        use std::str::FromStr;
-        assert!(LightningTime::from_str("f~3~a|8c").is_ok());
-        assert!(LightningTime::from_str("f~3~a|8").is_ok());
-        assert!(LightningTime::from_str("f~3~a").is_ok());
-        assert!(LightningTime::from_str("f~~|").is_err());
+        Ok(Self::from_parts(
+            group(bolts)?,
+            group(zaps)?,
+            group(sparks)?,
+            charges,
+            subcharges,
+        )
+        .normalized())
     }
 
-    #[test]
-    fn convert_to_real() {
-        let lightning = LightningTime {
-            bolts: 0x8,
-            ..Default::default()
+    /// Parses the labeled alternate [`Display`](core::fmt::Display) form with decimal values, like
+    /// `8 bolts, 0 zaps, 0 sparks, 0 charges, 0 subcharges`. Spacing, case, and singular labels
+    /// are accepted, and trailing levels can be left off like `{:#.2}` does.
+    pub fn parse_labeled(s: &str) -> Result<Self, Error> {
+        // Every part is a subslice of `s`, so its position is its offset from the start
+        let position = |part: &str| part.as_ptr() as usize - s.as_ptr() as usize;
+        let invalid = |part: &str| Error::InvalidFormat {
+            position: position(part),
         };
 
-        let naive: NaiveTime = lightning.into();
+        let mut components = [0; 5];
+        let mut parts = s.split(',');
+        for (component, label) in components.iter_mut().zip(LEVEL_LABELS) {
+            let Some(part) = parts.next() else {
+                break;
+            };
+            let part = part.trim();
+            let digits = part
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(part.len());
+            if digits == 0 {
+                return Err(invalid(part));
+            }
+            let name = part[digits..].trim_start();
+            let singular = &label[..label.len() - 1];
+            if !(name.eq_ignore_ascii_case(label) || name.eq_ignore_ascii_case(singular)) {
+                return Err(invalid(name));
+            }
+            let value = part[..digits].parse().map_err(|_| invalid(part))?;
+            *component = check_digit(label, value)?;
+        }
+        if let Some(extra) = parts.next() {
+            return Err(Error::InvalidFormat {
+                position: position(extra) - 1,
+            });
+        }
 
-        assert_eq!(naive, NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+        let [bolts, zaps, sparks, charges, subcharges] = components;
+        Ok(Self::from_parts(bolts, zaps, sparks, charges, subcharges))
+    }
 
-        let lightning = LightningTime {
-            bolts: 0x8,
-            charges: 0xa,
-            ..Default::default()
-        };
+    /// Whether this time is earlier in the day than `other`.
+    pub fn is_before(&self, other: LightningTime) -> bool {
+        *self < other
+    }
 
-        let naive: NaiveTime = lightning.into();
+    /// Whether this time is later in the day than `other`.
+    pub fn is_after(&self, other: LightningTime) -> bool {
+        *self > other
+    }
 
-        // Floating point is not fun
-        assert_eq!(
-            naive.second(),
-            NaiveTime::from_hms_opt(12, 0, 13).unwrap().second()
-        );
+    /// Whether this time is within `[start, end)`, wrapping past midnight if `start` is after
+    /// `end`, see [`LightningRange`].
+    pub fn is_between(&self, start: LightningTime, end: LightningTime) -> bool {
+        LightningRange::new(start, end).contains(*self)
+    }
+
+    /// Whether the two times are at most `tolerance_subcharges` apart, in whichever direction around
+    /// midnight is shorter.
+    pub fn approx_eq(&self, other: &Self, tolerance_subcharges: u32) -> bool {
+        let forward = (*other - *self).as_subcharges();
+        forward.min(Self::SUBCHARGES_PER_DAY - forward) <= tolerance_subcharges
+    }
+
+    /// Adds a signed number of subcharges, returning `None` if the result leaves the current day.
+    pub fn checked_add_subcharges(&self, n: i64) -> Option<LightningTime> {
+        let elapsed = (self.as_subcharges() as i64).checked_add(n)?;
+        (0..Self::SUBCHARGES_PER_DAY as i64)
+            .contains(&elapsed)
+            .then(|| Self::from_elapsed_subcharges(elapsed as u32))
+    }
+
+    /// Iterates over every time in a day that is a whole number of `step`s since midnight.
+    pub fn iter_day(step: LightningStep) -> impl Iterator<Item = LightningTime> {
+        (0..Self::SUBCHARGES_PER_DAY)
+            .step_by(step.subcharges() as usize)
+            .map(Self::from_elapsed_subcharges)
+    }
+
+    /// The next time that is a whole number of `level`s since midnight, always advancing even if
+    /// already on a boundary and wrapping to midnight after the last one.
+    pub fn next_boundary(&self, level: LightningStep) -> LightningTime {
+        let step = level.subcharges();
+        Self::from_elapsed_subcharges((self.as_subcharges() / step + 1) * step)
+    }
+
+    /// Like the `From<NaiveTime>` conversion, but rounds to the nearest subcharge instead of
+    /// truncating, wrapping to midnight at the end of the day.
+    #[cfg(feature = "chrono")]
+    pub fn from_naive_time_rounded(t: NaiveTime) -> Self {
+        Self::from_naive_time_rounded_to(t, LightningStep::Subcharge)
+    }
+
+    /// Rounds `t` to the nearest whole `level`, e.g. the nearest bolt, wrapping to midnight at the
+    /// end of the day. Uses the exact nanoseconds of `t`, so there is no intermediate rounding to
+    /// the subcharge.
+    #[cfg(feature = "chrono")]
+    pub fn from_naive_time_rounded_to(t: NaiveTime, level: LightningStep) -> Self {
+        let step = level.subcharges() as i128;
+        let steps = (naive_nanos(&t) * Self::SUBCHARGES_PER_DAY as i128 + NANOS_PER_DAY * step / 2)
+            / (NANOS_PER_DAY * step);
+
+        Self::from_elapsed_subcharges((steps * step) as u32)
+    }
+
+    /// Like the `From<NaiveTime>` conversion, but for a day that is `day_millis` long instead of
+    /// [`LightningTime::MILLIS_PER_DAY`], e.g. `88_775_244` for a Martian sol. `t` is the time
+    /// elapsed since midnight and must be shorter than the day.
+    #[cfg(feature = "chrono")]
+    pub fn from_naive_time_with_day_millis(t: NaiveTime, day_millis: u32) -> Result<Self, Error> {
+        let day_nanos = day_millis as i128 * 1_000_000;
+        let nanos = naive_nanos(&t);
+        if nanos >= day_nanos {
+            return Err(Error::OutOfDayRange);
+        }
+
+        Ok(Self::from_elapsed_subcharges(
+            (nanos * Self::SUBCHARGES_PER_DAY as i128 / day_nanos) as u32,
+        ))
+    }
+
+    /// Rounds `t` to the nearest subcharge boundary, wrapping to midnight at the end of the day.
+    #[cfg(feature = "chrono")]
+    pub fn snap_naive_time(t: NaiveTime) -> NaiveTime {
+        Self::from_naive_time_rounded(t).into()
+    }
+
+    /// How far `t` is through its subcharge, within `[0, 1)`, for animating between the truncated
+    /// times the `From<NaiveTime>` conversion gives.
+    #[cfg(feature = "chrono")]
+    pub fn subcharge_fraction(t: NaiveTime) -> f64 {
+        (naive_nanos(&t) * Self::SUBCHARGES_PER_DAY as i128 % NANOS_PER_DAY) as f64
+            / NANOS_PER_DAY as f64
+    }
+
+    /// Creates a Lightning Time from an offset since midnight, which must be within `[0, 24h)`.
+    #[cfg(feature = "chrono")]
+    pub fn from_duration_since_midnight(d: Duration) -> Result<Self, Error> {
+        Self::from_nanos_of_day(d.num_nanoseconds().ok_or(Error::OutOfDayRange)? as i128)
+    }
+
+    /// The offset since midnight represented by this time, rounded down to the nanosecond.
+    #[cfg(feature = "chrono")]
+    pub fn duration_since_midnight(&self) -> Duration {
+        Duration::nanoseconds(self.nanos_of_day() as i64)
+    }
+
+    /// Creates a Lightning Time from nanoseconds since midnight, which must be within `[0, 24h)`.
+    /// Rounds down to the subcharge using integer arithmetic.
+    pub fn from_nanos_of_day(nanos: i128) -> Result<Self, Error> {
+        if !(0..NANOS_PER_DAY).contains(&nanos) {
+            return Err(Error::OutOfDayRange);
+        }
+
+        Ok(Self::from_elapsed_nanos(nanos))
+    }
+
+    /// The exact nanoseconds since midnight represented by this time. A subcharge is not a whole
+    /// number of nanoseconds, so this is computed with integer arithmetic and rounded down.
+    pub fn nanos_of_day(&self) -> i128 {
+        self.as_subcharges() as i128 * NANOS_PER_DAY / Self::SUBCHARGES_PER_DAY as i128
+    }
+
+    /// The hours, minutes, seconds, and nanoseconds since midnight, from [`LightningTime::nanos_of_day`].
+    pub fn to_hms(&self) -> (u32, u32, u32, u32) {
+        let nanos = self.nanos_of_day();
+        let seconds = (nanos / 1_000_000_000) as u32;
+        (
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            (nanos % 1_000_000_000) as u32,
+        )
+    }
+
+    /// The number of subcharges elapsed since midnight, within `0..1048576` for in range components.
+    pub fn as_subcharges(&self) -> u32 {
+        (((self.bolts as u32 * 16 + self.zaps as u32) * 16 + self.sparks as u32) * 16
+            + self.charges as u32)
+            * 16
+            + self.subcharges as u32
+    }
+
+    /// How far through the day this time is, within `[0, 1)` for in range components.
+    pub fn as_fraction_of_day(&self) -> f64 {
+        self.as_subcharges() as f64 / Self::SUBCHARGES_PER_DAY as f64
+    }
+
+    /// Creates a Lightning Time from how far through the day it is, which must be within `[0, 1)`.
+    /// Rounds down to the subcharge.
+    pub fn from_fraction_of_day(f: f64) -> Result<Self, Error> {
+        if !(0.0..1.0).contains(&f) {
+            return Err(Error::OutOfDayRange);
+        }
+
+        Ok(Self::from_elapsed_subcharges(
+            (f * Self::SUBCHARGES_PER_DAY as f64) as u32,
+        ))
+    }
+
+    /// The number of subcharges left until the next midnight, so midnight itself has a full day.
+    pub fn subcharges_until_midnight(&self) -> u32 {
+        Self::SUBCHARGES_PER_DAY - self.as_subcharges() % Self::SUBCHARGES_PER_DAY
+    }
+
+    /// Creates a Lightning Time from the number of subcharges elapsed since midnight.
+    pub fn from_subcharges(n: u32) -> Result<Self, Error> {
+        if n >= Self::SUBCHARGES_PER_DAY {
+            return Err(Error::OutOfDayRange);
+        }
+
+        Ok(Self::from_elapsed_subcharges(n))
+    }
+
+    /// Truncates nanoseconds since midnight within `[0, 24h)` to the subcharge.
+    pub(crate) fn from_elapsed_nanos(nanos: i128) -> Self {
+        Self::from_elapsed_subcharges(
+            (nanos * Self::SUBCHARGES_PER_DAY as i128 / NANOS_PER_DAY) as u32,
+        )
+    }
+
+    pub(crate) fn from_elapsed_subcharges(elapsed: u32) -> Self {
+        let elapsed = elapsed % Self::SUBCHARGES_PER_DAY;
+        Self {
+            bolts: (elapsed >> 16) as u8,
+            zaps: (elapsed >> 12 & 0xf) as u8,
+            sparks: (elapsed >> 8 & 0xf) as u8,
+            charges: (elapsed >> 4 & 0xf) as u8,
+            subcharges: (elapsed & 0xf) as u8,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_stripped_string(&self) -> String {
+        format!("{:x}~{:x}~{:x}", self.bolts, self.zaps, self.sparks)
+    }
+
+    /// Writes the canonical form into `buf` without allocating, returning the number of bytes
+    /// written. Fails if `buf` is too small, in which case its contents are unspecified.
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        struct SliceWriter<'a> {
+            buf: &'a mut [u8],
+            len: usize,
+        }
+
+        impl core::fmt::Write for SliceWriter<'_> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                self.buf
+                    .get_mut(self.len..end)
+                    .ok_or(core::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut writer = SliceWriter { buf, len: 0 };
+        core::fmt::write(&mut writer, format_args!("{self}")).map_err(|_| Error::BufferTooSmall)?;
+
+        Ok(writer.len)
+    }
+
+    /// Like [`Display`](core::fmt::Display), but drops a zero subcharge and omits the charge section
+    /// entirely when both charges and subcharges are zero. Only digits that parse back as zero are
+    /// dropped, so this is the shortest string that parses back to this time.
+    #[cfg(feature = "std")]
+    #[doc(alias = "to_minimal_string")]
+    pub fn to_compact_string(&self) -> String {
+        match (self.charges, self.subcharges) {
+            (0, 0) => self.to_stripped_string(),
+            (charges, 0) => format!("{}|{:x}", self.to_stripped_string(), charges),
+            _ => self.to_string(),
+        }
+    }
+
+    /// A fixed width form for aligned columns, with the bolt, zap, and spark digits zero-padded to
+    /// two characters, e.g. `08~00~00|00`. Parse it back with [`LightningTime::parse_normalized`].
+    #[cfg(feature = "std")]
+    pub fn to_padded_string(&self) -> String {
+        format!(
+            "{:02x}~{:02x}~{:02x}|{:x}{:x}",
+            self.bolts, self.zaps, self.sparks, self.charges, self.subcharges
+        )
+    }
+
+    /// The current time in the local timezone.
+    #[cfg(feature = "chrono")]
+    pub fn now() -> Self {
+        Self::from(chrono::offset::Local::now().naive_local().time())
+    }
+
+    /// The current time in UTC.
+    #[cfg(feature = "chrono")]
+    pub fn now_utc() -> Self {
+        Self::from(chrono::Utc::now().naive_utc().time())
+    }
+
+    /// The current time in the given timezone.
+    #[cfg(feature = "chrono")]
+    pub fn now_in<Tz: TimeZone>(tz: &Tz) -> Self {
+        Self::from(chrono::Utc::now().with_timezone(tz))
+    }
+}
+
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+fn check_digit(component: &'static str, value: u8) -> Result<u8, Error> {
+    if value >= 16 {
+        return Err(Error::DigitOutOfRange { component, value });
+    }
+
+    Ok(value)
+}
+
+/// Nanoseconds since midnight, treating a leap second as the last nanosecond of the second before
+/// it so that times stay in order and within the day.
+#[cfg(feature = "chrono")]
+fn naive_nanos(value: &NaiveTime) -> i128 {
+    value.num_seconds_from_midnight() as i128 * 1_000_000_000
+        + value.nanosecond().min(999_999_999) as i128
+}
+
+/// Truncates to the subcharge. Leap seconds are clamped to the end of the second before them, so
+/// the result saturates at `f~f~f|ff` and never leaves the day, e.g. for `23:59:60.5`.
+#[cfg(feature = "chrono")]
+impl From<NaiveTime> for LightningTime {
+    fn from(value: NaiveTime) -> Self {
+        Self::from_elapsed_nanos(naive_nanos(&value))
+    }
+}
+
+/// Iterates over the components from bolts down to subcharges, see [`LightningTime::components`].
+impl IntoIterator for LightningTime {
+    type Item = u8;
+    type IntoIter = core::array::IntoIter<u8, 5>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components().into_iter()
+    }
+}
+
+/// Interprets the number as subcharges elapsed since midnight, see [`LightningTime::from_subcharges`].
+impl TryFrom<u32> for LightningTime {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::from_subcharges(value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl LightningTime {
+    /// Converts every time in `times` into the matching position of `out`, like the
+    /// `From<NaiveTime>` conversion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn from_naive_times(times: &[NaiveTime], out: &mut [LightningTime]) {
+        assert_eq!(times.len(), out.len(), "slices to have the same length");
+
+        for (time, out) in times.iter().zip(out) {
+            *out = Self::from(*time);
+        }
+    }
+}
+
+/// Uses the time-of-day in the datetime's own timezone.
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> From<DateTime<Tz>> for LightningTime {
+    fn from(value: DateTime<Tz>) -> Self {
+        Self::from(value.time())
+    }
+}
+
+/// Converts a stream of times to their colors, e.g. `times.into_iter().lightning_colors(&config)`.
+#[cfg(feature = "chrono")]
+pub trait LightningColorExt: Iterator<Item = NaiveTime> + Sized {
+    /// Maps each time to [`LightningTime::colors`], truncating like the `From<NaiveTime>`
+    /// conversion.
+    fn lightning_colors(
+        self,
+        config: &LightningTimeColorConfig,
+    ) -> impl Iterator<Item = LightningTimeColors> {
+        self.map(move |t| LightningTime::from(t).colors(config))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<I: Iterator<Item = NaiveTime>> LightningColorExt for I {}
+
+const PATTERN: &str = r"(?P<bolt>[[:xdigit:]])~(?P<zap>[[:xdigit:]])~(?P<spark>[[:xdigit:]])(?:\|(?P<charge>[[:xdigit:]])(?P<subcharge>[[:xdigit:]])?)?";
+
+#[cfg(feature = "std")]
+static RE: OnceLock<Regex> = OnceLock::new();
+
+#[cfg(feature = "std")]
+fn regex() -> &'static Regex {
+    RE.get_or_init(|| Regex::new(PATTERN).unwrap())
+}
+
+impl LightningTime {
+    /// The unanchored regular expression used by [`LightningTime::find`], with the named groups
+    /// `bolt`, `zap`, `spark`, `charge`, and `subcharge`.
+    pub const fn pattern() -> &'static str {
+        PATTERN
+    }
+}
+
+#[cfg(feature = "std")]
+impl LightningTime {
+    /// Finds the first Lightning Time anywhere in the given string, unlike [`FromStr`] which requires
+    /// the entire string to match. Digits are case-insensitive.
+    pub fn find(haystack: &str) -> Option<Self> {
+        regex()
+            .captures(haystack)
+            .map(|caps| Self::from_captures(&caps))
+    }
+
+    fn from_captures(caps: &Captures) -> Self {
+        LightningTime {
+            bolts: u8::from_str_radix(caps.name("bolt").unwrap().as_str(), 16).unwrap(),
+            zaps: u8::from_str_radix(caps.name("zap").unwrap().as_str(), 16).unwrap(),
+            sparks: u8::from_str_radix(caps.name("spark").unwrap().as_str(), 16).unwrap(),
+            charges: caps
+                .name("charge")
+                .map(|c| u8::from_str_radix(c.as_str(), 16).unwrap())
+                .unwrap_or(0),
+            subcharges: caps
+                .name("subcharge")
+                .map(|c| u8::from_str_radix(c.as_str(), 16).unwrap())
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Parses `bolt~zap~spark` followed by an optional `|charge` and then an optional subcharge digit,
+/// so a subcharge always requires a charge, e.g. `8~0~0|05` for charge 0 and subcharge 5. Digits
+/// are case-insensitive.
+impl FromStr for LightningTime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LightningFormat::default().parse(s)
+    }
+}
+
+/// The labels of the alternate [`Display`](core::fmt::Display) form, from bolts down.
+const LEVEL_LABELS: [&str; 5] = ["bolts", "zaps", "sparks", "charges", "subcharges"];
+
+/// Formats as `bolt~zap~spark|charge subcharge`. A precision limits how many levels are shown,
+/// e.g. `{:.3}` gives `f~3~a`. The alternate form `{:#}` labels each level instead, e.g.
+/// `15 bolts, 3 zaps, 10 sparks, 8 charges, 12 subcharges`.
+impl core::fmt::Display for LightningTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !f.alternate() {
+            return core::fmt::LowerHex::fmt(self, f);
+        }
+
+        let levels = LEVEL_LABELS.into_iter().zip(self.components());
+        for (i, (label, level)) in levels.take(f.precision().unwrap_or(5)).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_fmt(format_args!("{level} {label}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LightningTime {
+    /// Writes the number of levels given by the formatter's precision, or all five by default.
+    fn fmt_levels(&self, f: &mut core::fmt::Formatter<'_>, uppercase: bool) -> core::fmt::Result {
+        let levels = self.components();
+        let precision = f.precision().unwrap_or(levels.len());
+
+        for (i, level) in levels.into_iter().take(precision).enumerate() {
+            let separator = match i {
+                1 | 2 => "~",
+                3 => "|",
+                _ => "",
+            };
+            if uppercase {
+                f.write_fmt(format_args!("{separator}{level:X}"))?;
+            } else {
+                f.write_fmt(format_args!("{separator}{level:x}"))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Same as [`Display`](core::fmt::Display), e.g. `f~3~a|8c`.
+impl core::fmt::LowerHex for LightningTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_levels(f, false)
+    }
+}
+
+/// The [`Display`](core::fmt::Display) layout with uppercase digits, e.g. `F~3~A|8C`.
+impl core::fmt::UpperHex for LightningTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_levels(f, true)
+    }
+}
+
+/// Adds two times as subcharge counts, wrapping around at midnight.
+impl core::ops::Add for LightningTime {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_elapsed_subcharges(
+            self.as_subcharges() % Self::SUBCHARGES_PER_DAY
+                + rhs.as_subcharges() % Self::SUBCHARGES_PER_DAY,
+        )
+    }
+}
+
+impl core::ops::AddAssign for LightningTime {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtracts two times as subcharge counts, wrapping around at midnight.
+impl core::ops::Sub for LightningTime {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_elapsed_subcharges(
+            self.as_subcharges() % Self::SUBCHARGES_PER_DAY + Self::SUBCHARGES_PER_DAY
+                - rhs.as_subcharges() % Self::SUBCHARGES_PER_DAY,
+        )
+    }
+}
+
+impl core::ops::SubAssign for LightningTime {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LightningTime {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LightningTime {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = LightningTime;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("a Lightning Time string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                LightningTime::from_str(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// Variants may be added in the future, so matches need a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// No longer returned, conversions report one of the more specific variants instead.
+    #[deprecated(
+        since = "2.0.0",
+        note = "conversions return `OutOfDayRange` or `DigitOutOfRange` instead"
+    )]
+    #[error("Invalid conversion")]
+    InvalidConversion,
+    /// `position` is the byte offset of the first character that doesn't fit the format, or the
+    /// length of the input if it ended early.
+    #[error("Invalid Lightning Time format at position {position}")]
+    InvalidFormat { position: usize },
+    #[error("Value is outside of a single day")]
+    OutOfDayRange,
+    #[error("{component} must be a single hex digit, got {value}")]
+    DigitOutOfRange { component: &'static str, value: u8 },
+    #[error("Colors were not produced by the given color config")]
+    ColorMismatch,
+    #[error("Buffer is too small")]
+    BufferTooSmall,
+}
+
+#[cfg(feature = "chrono")]
+impl LightningTime {
+    /// Converts to a [`NaiveTime`], failing if any component is not a single hex digit.
+    pub fn try_into_naive_time(&self) -> Result<NaiveTime, Error> {
+        self.validate()?;
+
+        let nanos = self.nanos_of_day();
+        NaiveTime::from_num_seconds_from_midnight_opt(
+            (nanos / 1_000_000_000) as u32,
+            (nanos % 1_000_000_000) as u32,
+        )
+        .ok_or(Error::OutOfDayRange)
+    }
+
+    /// The reverse of [`LightningTime::from_naive_time_with_day_millis`]. Fails if any component
+    /// is not a single hex digit, or if the time is 24 hours or more into a longer day since a
+    /// [`NaiveTime`] can't represent it.
+    pub fn try_into_naive_time_with_day_millis(&self, day_millis: u32) -> Result<NaiveTime, Error> {
+        self.validate()?;
+
+        let nanos = self.as_subcharges() as i128 * day_millis as i128 * 1_000_000
+            / Self::SUBCHARGES_PER_DAY as i128;
+        NaiveTime::from_num_seconds_from_midnight_opt(
+            u32::try_from(nanos / 1_000_000_000).map_err(|_| Error::OutOfDayRange)?,
+            (nanos % 1_000_000_000) as u32,
+        )
+        .filter(|_| nanos < NANOS_PER_DAY)
+        .ok_or(Error::OutOfDayRange)
+    }
+}
+
+/// # Panics
+///
+/// Panics if any component is outside of `0..16`, see [`LightningTime::try_into_naive_time`].
+#[cfg(feature = "chrono")]
+impl From<LightningTime> for NaiveTime {
+    fn from(value: LightningTime) -> Self {
+        value
+            .try_into_naive_time()
+            .expect("Lightning Time to never overflow")
+    }
+}
+
+/// The offset since midnight, see [`LightningTime::duration_since_midnight`].
+#[cfg(feature = "chrono")]
+impl From<LightningTime> for Duration {
+    fn from(value: LightningTime) -> Self {
+        value.duration_since_midnight()
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Time> for LightningTime {
+    fn from(value: time::Time) -> Self {
+        let (hours, minutes, seconds, nanos) = value.as_hms_nano();
+        let nanos = (hours as i128 * 3600 + minutes as i128 * 60 + seconds as i128) * 1_000_000_000
+            + nanos as i128;
+
+        Self::from_elapsed_nanos(nanos)
+    }
+}
+
+#[cfg(feature = "time")]
+impl LightningTime {
+    /// Converts to a [`time::Time`], failing if any component is not a single hex digit.
+    pub fn try_into_time(&self) -> Result<time::Time, Error> {
+        self.validate()?;
+
+        let (hours, minutes, seconds, nanos) = self.to_hms();
+        time::Time::from_hms_nano(hours as u8, minutes as u8, seconds as u8, nanos)
+            .map_err(|_| Error::OutOfDayRange)
+    }
+}
+
+/// # Panics
+///
+/// Panics if any component is outside of `0..16`, see [`LightningTime::try_into_time`].
+#[cfg(feature = "time")]
+impl From<LightningTime> for time::Time {
+    fn from(value: LightningTime) -> Self {
+        value
+            .try_into_time()
+            .expect("Lightning Time to never overflow")
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<jiff::civil::Time> for LightningTime {
+    fn from(value: jiff::civil::Time) -> Self {
+        let nanos =
+            (value.hour() as i128 * 3600 + value.minute() as i128 * 60 + value.second() as i128)
+                * 1_000_000_000
+                + value.subsec_nanosecond() as i128;
+
+        Self::from_elapsed_nanos(nanos)
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl LightningTime {
+    /// Converts to a [`jiff::civil::Time`], failing if any component is not a single hex digit.
+    pub fn try_into_jiff_time(&self) -> Result<jiff::civil::Time, Error> {
+        self.validate()?;
+
+        let (hours, minutes, seconds, nanos) = self.to_hms();
+        jiff::civil::Time::new(hours as i8, minutes as i8, seconds as i8, nanos as i32)
+            .map_err(|_| Error::OutOfDayRange)
+    }
+}
+
+/// # Panics
+///
+/// Panics if any component is outside of `0..16`, see [`LightningTime::try_into_jiff_time`].
+#[cfg(feature = "jiff")]
+impl From<LightningTime> for jiff::civil::Time {
+    fn from(value: LightningTime) -> Self {
+        value
+            .try_into_jiff_time()
+            .expect("Lightning Time to never overflow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "chrono")]
+    use chrono::{NaiveTime, Timelike};
+    use palette::Srgb;
+
+    use crate::{Error, LightningTime, LightningTimeColors};
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn convert_to_lightning() {
+        let real = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let lightning = LightningTime::from(real);
+        assert_eq!(
+            lightning,
+            LightningTime {
+                bolts: 0x8,
+                ..Default::default()
+            }
+        );
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(lightning.to_string(), "8~0~0|00");
+            assert_eq!(lightning.to_stripped_string(), "8~0~0");
+        }
+        assert_eq!(
+            lightning.colors(&Default::default()),
+            LightningTimeColors {
+                bolt: Srgb::new(0x80, 0xa1, 0x00),
+                zap: Srgb::new(0x32, 0x00, 0xd6),
+                spark: Srgb::new(0xf6, 0x85, 0x00),
+            }
+        );
+    }
+
+    #[test]
+    fn write_to() {
+        let lightning = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+
+        let mut buf = [0; 8];
+        assert_eq!(lightning.write_to(&mut buf), Ok(8));
+        assert_eq!(&buf, b"f~3~a|8c");
+
+        let mut buf = [0; 16];
+        assert_eq!(lightning.write_to(&mut buf), Ok(8));
+        assert_eq!(&buf[..8], b"f~3~a|8c");
+
+        let mut buf = [0; 7];
+        assert_eq!(lightning.write_to(&mut buf), Err(Error::BufferTooSmall));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn compact_string() {
+        assert_eq!(
+            LightningTime::from_parts(8, 0, 0, 3, 4).to_compact_string(),
+            "8~0~0|34"
+        );
+        assert_eq!(
+            LightningTime::from_parts(8, 0, 0, 0, 4).to_compact_string(),
+            "8~0~0|04"
+        );
+        assert_eq!(
+            LightningTime::new(8, 0, 0, 3).to_compact_string(),
+            "8~0~0|3"
+        );
+        assert_eq!(LightningTime::new(8, 0, 0, 0).to_compact_string(), "8~0~0");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hex_colors() {
+        assert_eq!(
+            LightningTime::new(8, 0, 0, 0)
+                .colors(&Default::default())
+                .to_hex_strings(),
+            ["#80a100", "#3200d6", "#f68500"]
+        );
+    }
+
+    #[test]
+    fn hsl_colors() {
+        let colors = LightningTime::new(8, 0, 0, 0).colors(&Default::default());
+
+        for hsl in colors.to_hsl() {
+            assert!(hsl.hue.into_degrees().is_finite());
+            assert!((0.0..=1.0).contains(&hsl.lightness));
+        }
+        for hsv in colors.to_hsv() {
+            assert!(hsv.hue.into_degrees().is_finite());
+            assert!((0.0..=1.0).contains(&hsv.value));
+        }
+
+        let [bolt, _, _] = colors.to_hsl();
+        assert!((bolt.hue.into_positive_degrees() - 72.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn oklch_colors() {
+        let colors = LightningTime::new(8, 0, 0, 0).colors(&Default::default());
+
+        for oklch in colors.to_oklch() {
+            assert!(oklch.l.is_finite());
+            assert!((0.0..=1.0).contains(&oklch.l));
+            assert!(oklch.chroma.is_finite());
+            assert!(oklch.hue.into_degrees().is_finite());
+        }
+    }
+
+    #[test]
+    fn parse() {
+        use core::str::FromStr;
+        assert!(LightningTime::from_str("f~3~a|8c").is_ok());
+        assert!(LightningTime::from_str("f~3~a|8").is_ok());
+        assert!(LightningTime::from_str("f~3~a").is_ok());
+        assert!(LightningTime::from_str("f~~|").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_subcharge_without_charge() {
+        use std::str::FromStr;
+        let lightning = LightningTime::from_str("8~0~0|0c").unwrap();
+        assert_eq!(lightning, LightningTime::from_parts(8, 0, 0, 0, 0xc));
+        assert_eq!(lightning.to_string(), "8~0~0|0c");
+
+        assert_eq!(
+            LightningTime::from_str("8~0~0|c").unwrap(),
+            LightningTime::new(8, 0, 0, 0xc)
+        );
+    }
+
+    #[test]
+    fn parse_requires_core_digits() {
+        use core::str::FromStr;
+        assert!(LightningTime::from_str("8~0~0").is_ok());
+        assert!(LightningTime::from_str("8~0").is_err());
+        assert!(LightningTime::from_str("8~0~").is_err());
+        assert!(LightningTime::from_str("8~0|00").is_err());
+        assert!(LightningTime::from_str("8").is_err());
+        assert!(LightningTime::from_str("").is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        use core::str::FromStr;
+        for (invalid, position) in [
+            ("f~~|", 2),
+            ("g~0~0", 0),
+            ("", 0),
+            ("8", 1),
+            ("8~0", 3),
+            ("8~0~", 4),
+            ("8-0-0", 1),
+            ("8~0~0|", 6),
+            ("8~0~0-00", 5),
+            ("8~0~0|0g", 7),
+            ("8~0~0|000", 8),
+            ("8~00~0", 3),
+        ] {
+            assert_eq!(
+                LightningTime::from_str(invalid),
+                Err(Error::InvalidFormat { position })
+            );
+        }
+        assert_eq!(
+            LightningTime::from_subcharges(0x100000),
+            Err(Error::OutOfDayRange)
+        );
+        assert_eq!(
+            LightningTime::try_new(0, 0, 0, 0, 0x10),
+            Err(Error::DigitOutOfRange {
+                component: "subcharges",
+                value: 0x10
+            })
+        );
+    }
+
+    #[test]
+    fn parse_garbage() {
+        use core::str::FromStr;
+        assert!(LightningTime::from_str("8~0~0|00 hello").is_err());
+        assert!(LightningTime::from_str("garbage 8~0~0").is_err());
+        assert!(LightningTime::from_str("8~0~0|000").is_err());
+        assert!(LightningTime::from_str(" 8~0~0").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn find() {
+        assert_eq!(
+            LightningTime::find("garbage 8~0~0|00 hello"),
+            Some(LightningTime::new(8, 0, 0, 0))
+        );
+        assert_eq!(LightningTime::find("garbage"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_subcharges() {
+        use std::str::FromStr;
+        let lightning = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(lightning.to_string(), "f~3~a|8c");
+        assert_eq!(LightningTime::from_str("f~3~a|8c").unwrap(), lightning);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn round_trip_string() {
+        use std::str::FromStr;
+        for bolts in 0..16 {
+            for zaps in 0..16 {
+                for sparks in 0..16 {
+                    for charges in 0..16 {
+                        for subcharges in 0..16 {
+                            let lightning = LightningTime {
+                                bolts,
+                                zaps,
+                                sparks,
+                                charges,
+                                subcharges,
+                            };
+                            assert_eq!(
+                                LightningTime::from_str(&lightning.to_string()).unwrap(),
+                                lightning
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn convert_to_lightning_sweep() {
+        for millis in (0..86_400_000).step_by(997) {
+            let time = NaiveTime::from_num_seconds_from_midnight_opt(
+                millis / 1_000,
+                millis % 1_000 * 1_000_000,
+            )
+            .unwrap();
+            let lightning = LightningTime::from(time);
+
+            let subcharges = (millis as u64 * 1048576 / 86_400_000) as u32;
+            assert_eq!(lightning.as_subcharges(), subcharges);
+            assert_eq!(
+                [
+                    lightning.bolts,
+                    lightning.zaps,
+                    lightning.sparks,
+                    lightning.charges,
+                    lightning.subcharges
+                ],
+                [16, 12, 8, 4, 0].map(|shift| (subcharges >> shift & 0xf) as u8)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn convert_to_lightning_rounded() {
+        let early = NaiveTime::from_hms_milli_opt(0, 0, 0, 40).unwrap();
+        assert_eq!(LightningTime::from(early), LightningTime::default());
+        assert_eq!(
+            LightningTime::from_naive_time_rounded(early),
+            LightningTime::default()
+        );
+
+        let late = NaiveTime::from_hms_milli_opt(0, 0, 0, 80).unwrap();
+        assert_eq!(LightningTime::from(late), LightningTime::default());
+        assert_eq!(
+            LightningTime::from_naive_time_rounded(late),
+            LightningTime::from_parts(0, 0, 0, 0, 1)
+        );
+
+        let end = NaiveTime::from_hms_milli_opt(23, 59, 59, 990).unwrap();
+        assert_eq!(
+            LightningTime::from(end),
+            LightningTime::from_parts(15, 15, 15, 15, 15)
+        );
+        assert_eq!(
+            LightningTime::from_naive_time_rounded(end),
+            LightningTime::default()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn convert_to_lightning_rounded_to() {
+        use crate::LightningStep;
+
+        let time = NaiveTime::from_hms_opt(12, 50, 0).unwrap();
+        assert_eq!(
+            LightningTime::from_naive_time_rounded_to(time, LightningStep::Bolt),
+            LightningTime::new(9, 0, 0, 0)
+        );
+        assert_eq!(
+            LightningTime::from_naive_time_rounded_to(time, LightningStep::Zap),
+            LightningTime::new(8, 9, 0, 0)
+        );
+
+        let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+        assert_eq!(
+            LightningTime::from_naive_time_rounded_to(leap, LightningStep::Subcharge),
+            LightningTime::MIDNIGHT
+        );
+        assert_eq!(
+            LightningTime::from_naive_time_rounded_to(leap, LightningStep::Bolt),
+            LightningTime::MIDNIGHT
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn convert_to_real() {
+        let lightning = LightningTime {
+            bolts: 0x8,
+            ..Default::default()
+        };
+
+        let naive: NaiveTime = lightning.into();
+
+        assert_eq!(naive, NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+
+        let lightning = LightningTime {
+            bolts: 0x8,
+            charges: 0xa,
+            ..Default::default()
+        };
+
+        let naive: NaiveTime = lightning.into();
+
+        assert_eq!(
+            naive,
+            NaiveTime::from_hms_nano_opt(12, 0, 13, 183_593_750).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn convert_to_real_exact() {
+        for (lightning, naive) in [
+            (
+                LightningTime::new(1, 0, 0, 0),
+                NaiveTime::from_hms_opt(1, 30, 0),
+            ),
+            (
+                LightningTime::new(0, 1, 0, 0),
+                NaiveTime::from_hms_milli_opt(0, 5, 37, 500),
+            ),
+            (
+                LightningTime::new(0, 0, 1, 0),
+                NaiveTime::from_hms_micro_opt(0, 0, 21, 93_750),
+            ),
+            (
+                LightningTime::new(0xf, 0, 0, 0),
+                NaiveTime::from_hms_opt(22, 30, 0),
+            ),
+            (
+                LightningTime::new(0, 0, 0, 1),
+                NaiveTime::from_hms_nano_opt(0, 0, 1, 318_359_375),
+            ),
+        ] {
+            assert_eq!(NaiveTime::from(lightning), naive.unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn checked_convert_to_real() {
+        assert_eq!(
+            LightningTime::new(16, 0, 0, 0).try_into_naive_time(),
+            Err(Error::DigitOutOfRange {
+                component: "bolts",
+                value: 16
+            })
+        );
+
+        let max = LightningTime {
+            bolts: 15,
+            zaps: 15,
+            sparks: 15,
+            charges: 15,
+            subcharges: 15,
+        };
+        let naive = max.try_into_naive_time().unwrap();
+        assert_eq!(naive.hour(), 23);
+        assert_eq!(naive.minute(), 59);
+        assert_eq!(naive.second(), 59);
+    }
+
+    #[test]
+    fn iter_day() {
+        use crate::LightningStep;
+
+        assert_eq!(LightningTime::iter_day(LightningStep::Bolt).count(), 16);
+        let mut bolts = LightningTime::iter_day(LightningStep::Bolt);
+        assert_eq!(bolts.next(), Some(LightningTime::default()));
+        assert_eq!(bolts.nth(7), Some(LightningTime::new(8, 0, 0, 0)));
+        assert_eq!(bolts.last(), Some(LightningTime::new(15, 0, 0, 0)));
+
+        let sparks = LightningTime::iter_day(LightningStep::Spark);
+        assert_eq!(sparks.count(), 16 * 16 * 16);
+
+        let mut subcharges = LightningTime::iter_day(LightningStep::Subcharge);
+        assert_eq!(
+            subcharges.nth(0x12345),
+            Some(LightningTime::from_parts(1, 2, 3, 4, 5))
+        );
+        assert_eq!(
+            subcharges.last(),
+            Some(LightningTime::from_parts(15, 15, 15, 15, 15))
+        );
+    }
+
+    #[test]
+    fn const_new() {
+        const NOON: LightningTime = LightningTime::new(8, 0, 0, 0);
+        const TABLE: [LightningTime; 2] = [
+            LightningTime::new(0, 0, 0, 0),
+            LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc),
+        ];
+
+        assert_eq!(NOON.bolts, 8);
+        assert_eq!(TABLE[0], LightningTime::default());
+        assert_eq!(TABLE[1].subcharges, 0xc);
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(LightningTime::try_new(15, 15, 15, 15, 15).is_ok());
+
+        for (i, component) in ["bolts", "zaps", "sparks", "charges", "subcharges"]
+            .into_iter()
+            .enumerate()
+        {
+            let mut values = [15; 5];
+            values[i] = 16;
+            let [b, z, s, c, sc] = values;
+            assert!(matches!(
+                LightningTime::try_new(b, z, s, c, sc),
+                Err(Error::DigitOutOfRange { component: found, value: 16 }) if found == component
+            ));
+        }
+    }
+
+    #[test]
+    fn presets() {
+        use crate::LightningTimeColorConfig;
+
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        let presets = [
+            LightningTimeColorConfig::default(),
+            LightningTimeColorConfig::solarized(),
+            LightningTimeColorConfig::monochrome(),
+        ];
+        for (i, a) in presets.iter().enumerate() {
+            for b in &presets[i + 1..] {
+                assert_ne!(time.colors(a), time.colors(b));
+            }
+        }
+
+        for name in LightningTimeColorConfig::PRESETS {
+            assert!(LightningTimeColorConfig::preset(name).is_some());
+        }
+        assert_eq!(
+            LightningTimeColorConfig::preset("solarized"),
+            Some(LightningTimeColorConfig::solarized())
+        );
+        assert_eq!(LightningTimeColorConfig::preset("nope"), None);
+
+        assert_eq!(
+            time.colors(&LightningTimeColorConfig::monochrome()),
+            LightningTimeColors {
+                bolt: Srgb::new(0, 0xf3, 0),
+                zap: Srgb::new(0, 0x3a, 0),
+                spark: Srgb::new(0, 0xa8, 0),
+            }
+        );
+    }
+
+    #[test]
+    fn config_builder() {
+        use crate::{LightningBaseColors, LightningTimeColorConfig};
+
+        assert_eq!(
+            LightningTimeColorConfig::builder()
+                .bolt(161, 0)
+                .zap(50, 214)
+                .spark(246, 133)
+                .build(),
+            LightningTimeColorConfig::default()
+        );
+
+        let config = LightningTimeColorConfig::builder().zap(1, 2).build();
+        assert_eq!(config.zap, LightningBaseColors(1, 2));
+        assert_eq!(config.bolt, LightningTimeColorConfig::default().bolt);
+    }
+
+    #[test]
+    fn interpolated_colors() {
+        let config = Default::default();
+        let before = LightningTime::from_parts(8, 0, 3, 15, 15);
+        let after = LightningTime::new(8, 0, 4, 0);
+
+        let quantized = |time: LightningTime| time.colors(&config).spark.into_format::<f32>().blue;
+        let [_, _, spark] = before.colors_interpolated(&config, 0.5);
+        assert!(quantized(before) < spark.blue && spark.blue < quantized(after));
+
+        let [bolt, zap, spark] = after.colors_interpolated(&config, 0.);
+        let colors = after.colors(&config);
+        let floor = |c: Srgb| {
+            Srgb::<u8>::new(
+                (c.red * 255.) as u8,
+                (c.green * 255.) as u8,
+                (c.blue * 255.) as u8,
+            )
+        };
+        assert_eq!(floor(bolt), colors.bolt);
+        assert_eq!(floor(zap), colors.zap);
+        assert_eq!(floor(spark), colors.spark);
+    }
+
+    #[test]
+    fn interpolated_colors_out_of_range() {
+        let config = Default::default();
+        let time = LightningTime::from_parts(8, 0, 3, 15, 15);
+
+        assert_eq!(
+            time.colors_interpolated(&config, -3.),
+            time.colors_interpolated(&config, 0.)
+        );
+        assert_eq!(
+            time.colors_interpolated(&config, 40.),
+            time.colors_interpolated(&config, 1.)
+        );
+        assert_eq!(
+            time.colors_interpolated(&config, f64::NAN),
+            time.colors_interpolated(&config, 0.)
+        );
+    }
+
+    #[test]
+    fn colors_to_lightning() {
+        use crate::LightningTimeColorConfig;
+
+        let time = LightningTime::new(0xf, 0x3, 0xa, 0x8);
+        for config in [
+            LightningTimeColorConfig::default(),
+            LightningTimeColorConfig::solarized(),
+            LightningTimeColorConfig::monochrome(),
+        ] {
+            assert_eq!(
+                time.colors(&config).try_to_lightning_time(&config),
+                Ok(time)
+            );
+        }
+
+        let colors = time.colors(&Default::default());
+        assert_eq!(
+            colors.try_to_lightning_time(&LightningTimeColorConfig::solarized()),
+            Err(Error::ColorMismatch)
+        );
+        assert_eq!(
+            LightningTimeColors {
+                zap: Srgb::new(0x32, 0x00, 0xd6),
+                ..colors
+            }
+            .try_to_lightning_time(&Default::default()),
+            Err(Error::ColorMismatch)
+        );
+    }
+
+    #[test]
+    fn swapped_channels() {
+        use crate::{LightningChannel, LightningChannels, LightningTimeColorConfig};
+
+        let config = LightningTimeColorConfig {
+            channels: LightningChannels {
+                bolt: LightningChannel::Blue,
+                zap: LightningChannel::Green,
+                spark: LightningChannel::Red,
+            },
+            ..Default::default()
+        };
+        let noon = LightningTime::new(8, 0, 0, 0);
+        assert_ne!(noon.colors(&config), noon.colors(&Default::default()));
+        assert_eq!(
+            noon.colors(&config),
+            LightningTimeColors {
+                bolt: Srgb::new(0xa1, 0x00, 0x80),
+                zap: Srgb::new(0x32, 0x00, 0xd6),
+                spark: Srgb::new(0x00, 0xf6, 0x85),
+            }
+        );
+    }
+
+    #[test]
+    fn colors_out_of_range() {
+        let max = LightningTime {
+            bolts: 15,
+            zaps: 15,
+            ..Default::default()
+        };
+        assert_eq!(
+            max.colors(&Default::default()).bolt,
+            Srgb::new(0xff, 0xa1, 0x00)
+        );
+        assert!(max.checked_colors(&Default::default()).is_some());
+
+        let overflow = LightningTime {
+            bolts: 0x1f,
+            zaps: 0x2f,
+            ..Default::default()
+        };
+        assert_eq!(
+            overflow.colors(&Default::default()),
+            max.colors(&Default::default())
+        );
+        assert!(overflow.checked_colors(&Default::default()).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let lightning = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        let json = serde_json::to_string(&lightning).unwrap();
+        assert_eq!(json, "\"f~3~a|8c\"");
+        assert_eq!(
+            serde_json::from_str::<LightningTime>(&json).unwrap(),
+            lightning
+        );
+
+        assert!(serde_json::from_str::<LightningTime>("\"f~~|\"").is_err());
+        assert!(serde_json::from_str::<LightningTime>("8").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_color_config() {
+        use crate::LightningTimeColorConfig;
+
+        let config = LightningTimeColorConfig::default();
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            r#"{"bolt":[161,0],"zap":[50,214],"spark":[246,133],"channels":{"bolt":"red","zap":"green","spark":"blue"}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<LightningTimeColorConfig>(&json).unwrap(),
+            config
+        );
+        assert_eq!(
+            serde_json::from_str::<LightningTimeColorConfig>(
+                r#"{"bolt":[161,0],"zap":[50,214],"spark":[246,133]}"#
+            )
+            .unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let max = LightningTime::from_parts(15, 15, 15, 15, 15);
+        let one = LightningTime::from_parts(0, 0, 0, 0, 1);
+        assert_eq!(max + one, LightningTime::default());
+        assert_eq!(LightningTime::default() - one, max);
+
+        let mut lightning = LightningTime::new(8, 0, 0, 0);
+        lightning += LightningTime::from_parts(0, 0, 0, 15, 15);
+        lightning += one;
+        assert_eq!(lightning, LightningTime::new(8, 0, 1, 0));
+        lightning -= LightningTime::new(9, 0, 0, 0);
+        assert_eq!(lightning, LightningTime::new(15, 0, 1, 0));
+
+        let overflow = LightningTime::from_parts(0, 0, 0, 0, 17);
+        assert_eq!(
+            overflow + LightningTime::default(),
+            LightningTime::from_parts(0, 0, 0, 1, 1)
+        );
+    }
+
+    #[test]
+    fn checked_add_subcharges() {
+        let max = LightningTime::from_parts(15, 15, 15, 15, 14);
+        assert_eq!(
+            max.checked_add_subcharges(1),
+            Some(LightningTime::from_parts(15, 15, 15, 15, 15))
+        );
+        assert_eq!(max.checked_add_subcharges(2), None);
+
+        let start = LightningTime::from_parts(0, 0, 0, 1, 0);
+        assert_eq!(
+            start.checked_add_subcharges(-16),
+            Some(LightningTime::default())
+        );
+        assert_eq!(start.checked_add_subcharges(-17), None);
+        assert_eq!(
+            start.checked_add_subcharges(16 * 16),
+            Some(LightningTime::from_parts(0, 0, 1, 1, 0))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn duration_since_midnight() {
+        use chrono::Duration;
+
+        let noon = LightningTime::from_duration_since_midnight(Duration::hours(12)).unwrap();
+        assert_eq!(noon, LightningTime::new(8, 0, 0, 0));
+        assert_eq!(noon.duration_since_midnight(), Duration::hours(12));
+
+        assert_eq!(
+            LightningTime::from_duration_since_midnight(Duration::zero()).unwrap(),
+            LightningTime::default()
+        );
+        assert!(matches!(
+            LightningTime::from_duration_since_midnight(Duration::hours(24)),
+            Err(Error::OutOfDayRange)
+        ));
+        assert!(matches!(
+            LightningTime::from_duration_since_midnight(Duration::nanoseconds(-1)),
+            Err(Error::OutOfDayRange)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn convert_datetime() {
+        use chrono::{FixedOffset, TimeZone, Utc};
+
+        let noon = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(LightningTime::from(noon), LightningTime::new(8, 0, 0, 0));
+
+        let offset = noon.with_timezone(&FixedOffset::east_opt(6 * 60 * 60).unwrap());
+        assert_eq!(
+            LightningTime::from(offset),
+            LightningTime::new(0xc, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn now() {
+        use chrono::{FixedOffset, Utc};
+
+        for lightning in [
+            LightningTime::now(),
+            LightningTime::now_utc(),
+            LightningTime::now_in(&Utc),
+            LightningTime::now_in(&FixedOffset::west_opt(5 * 60 * 60).unwrap()),
+        ] {
+            assert!(lightning.try_into_naive_time().is_ok());
+        }
+    }
+
+    #[test]
+    fn subcharges() {
+        assert_eq!(LightningTime::default().as_subcharges(), 0);
+        assert_eq!(LightningTime::new(8, 0, 0, 0).as_subcharges(), 0x80000);
+        assert_eq!(
+            LightningTime::from_parts(15, 15, 15, 15, 15).as_subcharges(),
+            0xfffff
+        );
+
+        for lightning in [
+            LightningTime::default(),
+            LightningTime::new(8, 0, 0, 0),
+            LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc),
+            LightningTime::from_parts(15, 15, 15, 15, 15),
+        ] {
+            assert_eq!(
+                LightningTime::from_subcharges(lightning.as_subcharges()).unwrap(),
+                lightning
+            );
+        }
+
+        assert!(matches!(
+            LightningTime::from_subcharges(0x100000),
+            Err(Error::OutOfDayRange)
+        ));
+    }
+
+    #[test]
+    fn ordering() {
+        let sorted = [
+            LightningTime::default(),
+            LightningTime::from_parts(0, 0, 0, 0, 15),
+            LightningTime::from_parts(0, 0, 0, 1, 0),
+            LightningTime::new(0, 15, 15, 15),
+            LightningTime::new(1, 0, 0, 0),
+            LightningTime::new(8, 0, 0, 0),
+            LightningTime::from_parts(15, 15, 15, 15, 15),
+        ];
+        for pair in sorted.windows(2) {
+            assert!(pair[0].as_subcharges() < pair[1].as_subcharges());
+        }
+
+        let mut shuffled = [
+            sorted[4], sorted[0], sorted[6], sorted[2], sorted[5], sorted[1], sorted[3],
+        ];
+        shuffled.sort();
+        assert_eq!(shuffled, sorted);
+        assert_eq!(shuffled.iter().max(), Some(&sorted[6]));
+        assert_eq!(shuffled.iter().min(), Some(&sorted[0]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let set: HashSet<_> = [
+            LightningTime::default(),
+            LightningTime::new(8, 0, 0, 0),
+            LightningTime::new(8, 0, 0, 0),
+            LightningTime::from_parts(8, 0, 0, 0, 1),
+            LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(&LightningTime::new(8, 0, 0, 0)));
+        assert!(!set.contains(&LightningTime::new(9, 0, 0, 0)));
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(
+            LightningTime::SUBCHARGES_PER_DAY,
+            (LightningTime::UNITS_PER_LEVEL as u32).pow(5)
+        );
+        assert_eq!(
+            LightningTime::NANOS_PER_SUBCHARGE * LightningTime::SUBCHARGES_PER_DAY as f64,
+            86_400_000_000_000.
+        );
+        assert_eq!(
+            LightningTime::MILLIS_PER_SUBCHARGE * 1_000_000.,
+            LightningTime::NANOS_PER_SUBCHARGE
+        );
+        assert_eq!(
+            LightningTime::from_subcharges(LightningTime::SUBCHARGES_PER_DAY - 1).unwrap(),
+            LightningTime::from_parts(0xf, 0xf, 0xf, 0xf, 0xf)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hex_case() {
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(format!("{time:x}"), "f~3~a|8c");
+        assert_eq!(format!("{time:X}"), "F~3~A|8C");
+        assert_eq!(format!("{time:x}"), time.to_string());
+    }
+
+    #[test]
+    fn parse_uppercase() {
+        use core::str::FromStr;
+
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(LightningTime::from_str("F~3~A|8C"), Ok(time));
+        assert_eq!(LightningTime::from_str("f~3~a|8c"), Ok(time));
+        assert_eq!(LightningTime::from_str("F~3~a|8C"), Ok(time));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn find_uppercase() {
+        assert_eq!(
+            LightningTime::find("logged at F~3~A|8C"),
+            Some(LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc))
+        );
+    }
+
+    #[test]
+    fn nanos_of_day() {
+        let noon = LightningTime::new(8, 0, 0, 0);
+        assert_eq!(noon.nanos_of_day(), 43_200_000_000_000);
+        assert_eq!(
+            LightningTime::from_nanos_of_day(43_200_000_000_000),
+            Ok(noon)
+        );
+
+        let one = LightningTime::from_parts(0, 0, 0, 0, 1);
+        assert_eq!(one.nanos_of_day(), 82_397_460);
+        assert_eq!(
+            LightningTime::from_nanos_of_day(82_397_460),
+            Ok(LightningTime::default())
+        );
+        assert_eq!(LightningTime::from_nanos_of_day(82_397_461), Ok(one));
+
+        assert_eq!(
+            LightningTime::from_nanos_of_day(86_400_000_000_000),
+            Err(Error::OutOfDayRange)
+        );
+        assert_eq!(
+            LightningTime::from_nanos_of_day(-1),
+            Err(Error::OutOfDayRange)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn convert_time() {
+        let noon = time::Time::from_hms(12, 0, 0).unwrap();
+        assert_eq!(LightningTime::from(noon), LightningTime::new(8, 0, 0, 0));
+        assert_eq!(time::Time::from(LightningTime::new(8, 0, 0, 0)), noon);
+        assert_eq!(
+            time::Time::from(LightningTime::new(8, 0, 0, 0xa)),
+            time::Time::from_hms_nano(12, 0, 13, 183_593_750).unwrap()
+        );
+        assert_eq!(
+            LightningTime::new(16, 0, 0, 0).try_into_time(),
+            Err(Error::DigitOutOfRange {
+                component: "bolts",
+                value: 16
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "jiff")]
+    fn convert_jiff() {
+        use jiff::civil::Time;
+
+        for (lightning, civil) in [
+            (LightningTime::default(), Time::midnight()),
+            (
+                LightningTime::new(8, 0, 0, 0),
+                Time::new(12, 0, 0, 0).unwrap(),
+            ),
+        ] {
+            assert_eq!(LightningTime::from(civil), lightning);
+            assert_eq!(Time::from(lightning), civil);
+        }
+    }
+
+    #[test]
+    fn next_boundary() {
+        use crate::LightningStep;
+
+        let time = LightningTime::new(8, 5, 0, 0);
+        assert_eq!(
+            time.next_boundary(LightningStep::Bolt),
+            LightningTime::new(9, 0, 0, 0)
+        );
+        assert_eq!(
+            time.next_boundary(LightningStep::Zap),
+            LightningTime::new(8, 6, 0, 0)
+        );
+        assert_eq!(
+            time.next_boundary(LightningStep::Subcharge),
+            LightningTime::from_parts(8, 5, 0, 0, 1)
+        );
+        assert_eq!(
+            LightningTime::from_parts(8, 5, 3, 2, 1).next_boundary(LightningStep::Spark),
+            LightningTime::new(8, 5, 4, 0)
+        );
+        assert_eq!(
+            LightningTime::new(0xf, 2, 0, 0).next_boundary(LightningStep::Bolt),
+            LightningTime::default()
+        );
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(
+            LightningTime::new(0, 0, 0, 20).normalized(),
+            LightningTime::new(0, 0, 1, 4)
+        );
+        assert_eq!(
+            LightningTime::from_parts(0, 0xf, 0xf, 0xf, 0x10).normalized(),
+            LightningTime::new(1, 0, 0, 0)
+        );
+        assert_eq!(
+            LightningTime::new(0x11, 0, 0, 0).normalized(),
+            LightningTime::new(1, 0, 0, 0)
+        );
+        assert_eq!(
+            LightningTime::from_parts(255, 255, 255, 255, 255).normalized(),
+            LightningTime::from_parts(0xf, 0xf, 0xf, 0xe, 0xf)
+        );
+
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(time.normalized(), time);
+    }
+
+    #[test]
+    fn fraction_of_day() {
+        assert_eq!(LightningTime::default().as_fraction_of_day(), 0.0);
+        assert_eq!(LightningTime::new(8, 0, 0, 0).as_fraction_of_day(), 0.5);
+        assert_eq!(LightningTime::new(4, 0, 0, 0).as_fraction_of_day(), 0.25);
+
+        assert_eq!(
+            LightningTime::from_fraction_of_day(0.5),
+            Ok(LightningTime::new(8, 0, 0, 0))
+        );
+        assert_eq!(
+            LightningTime::from_fraction_of_day(0.0),
+            Ok(LightningTime::default())
+        );
+        assert_eq!(
+            LightningTime::from_fraction_of_day(1.0),
+            Err(Error::OutOfDayRange)
+        );
+        assert_eq!(
+            LightningTime::from_fraction_of_day(-0.1),
+            Err(Error::OutOfDayRange)
+        );
+        assert_eq!(
+            LightningTime::from_fraction_of_day(f64::NAN),
+            Err(Error::OutOfDayRange)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn minimal_string() {
+        use core::str::FromStr;
+
+        for (time, minimal) in [
+            (LightningTime::new(8, 0, 0, 0), "8~0~0"),
+            (LightningTime::new(8, 0, 0, 3), "8~0~0|3"),
+            (LightningTime::from_parts(8, 0, 0, 0, 4), "8~0~0|04"),
+            (LightningTime::from_parts(8, 0, 0, 3, 4), "8~0~0|34"),
+        ] {
+            assert_eq!(time.to_compact_string(), minimal);
+            assert_eq!(LightningTime::from_str(minimal), Ok(time));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn display_precision() {
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(format!("{time:.3}"), "f~3~a");
+        assert_eq!(format!("{time:.4}"), "f~3~a|8");
+        assert_eq!(format!("{time:.5}"), "f~3~a|8c");
+        assert_eq!(format!("{time:.9}"), "f~3~a|8c");
+        assert_eq!(format!("{time:.1}"), "f");
+        assert_eq!(format!("{time:.3X}"), "F~3~A");
+    }
+
+    #[test]
+    fn parse_normalized() {
+        assert_eq!(
+            LightningTime::parse_normalized("8~0~1f"),
+            Ok(LightningTime::new(8, 1, 0xf, 0))
+        );
+        assert_eq!(
+            LightningTime::parse_normalized("8~12~0|1f"),
+            Ok(LightningTime::from_parts(9, 2, 0, 1, 0xf))
+        );
+        assert_eq!(
+            LightningTime::parse_normalized("1f~0~0|3"),
+            Ok(LightningTime::new(0xf, 0, 0, 3))
+        );
+        assert_eq!(
+            LightningTime::parse_normalized("F~3~A|8C"),
+            Ok(LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc))
+        );
+
+        let time = LightningTime::parse_normalized("ff~ff~ff|ff").unwrap();
+        for component in [
+            time.bolts,
+            time.zaps,
+            time.sparks,
+            time.charges,
+            time.subcharges,
+        ] {
+            assert!(component < 16);
+        }
+
+        for (invalid, position) in [
+            ("8~0", 3),
+            ("8~0~0~0", 5),
+            ("8~100~0", 2),
+            ("8~~0", 2),
+            ("8~+1~0", 2),
+            ("8~0~0|", 6),
+            ("8~0~0|123", 6),
+        ] {
+            assert_eq!(
+                LightningTime::parse_normalized(invalid),
+                Err(Error::InvalidFormat { position })
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_u32() {
+        assert_eq!(LightningTime::try_from(0), Ok(LightningTime::default()));
+        assert_eq!(
+            LightningTime::try_from(524288),
+            Ok(LightningTime::new(8, 0, 0, 0))
+        );
+        assert_eq!(LightningTime::try_from(1048576), Err(Error::OutOfDayRange));
+    }
+
+    #[test]
+    fn comparisons() {
+        let noon = LightningTime::new(8, 0, 0, 0);
+        let late = LightningTime::from_parts(8, 0, 0, 0, 1);
+        assert!(noon.is_before(late));
+        assert!(!late.is_before(noon));
+        assert!(late.is_after(noon));
+        assert!(!noon.is_after(noon));
+        assert!(!noon.is_before(noon));
+
+        assert!(noon.is_between(noon, late));
+        assert!(!late.is_between(noon, late));
+
+        let night = LightningTime::from_fraction_of_day(22. / 24.).unwrap();
+        let morning = LightningTime::from_fraction_of_day(2. / 24.).unwrap();
+        assert!(LightningTime::default().is_between(night, morning));
+        assert!(LightningTime::new(0xf, 0, 0, 0).is_between(night, morning));
+        assert!(LightningTime::new(1, 0, 0, 0).is_between(night, morning));
+        assert!(!noon.is_between(night, morning));
+    }
+
+    #[test]
+    fn subcharges_until_midnight() {
+        assert_eq!(
+            LightningTime::default().subcharges_until_midnight(),
+            LightningTime::SUBCHARGES_PER_DAY
+        );
+        assert_eq!(
+            LightningTime::new(8, 0, 0, 0).subcharges_until_midnight(),
+            524288
+        );
+        assert_eq!(
+            LightningTime::from_parts(0xf, 0xf, 0xf, 0xf, 0xf).subcharges_until_midnight(),
+            1
+        );
+    }
+
+    #[test]
+    fn with_components() {
+        let time = LightningTime::from_parts(8, 0, 0, 0, 0);
+        assert_eq!(time.with_bolts(0xf), Ok(LightningTime::new(0xf, 0, 0, 0)));
+        assert_eq!(time.with_zaps(3), Ok(LightningTime::new(8, 3, 0, 0)));
+        assert_eq!(time.with_sparks(0xa), Ok(LightningTime::new(8, 0, 0xa, 0)));
+        assert_eq!(time.with_charges(5), Ok(LightningTime::new(8, 0, 0, 5)));
+        assert_eq!(
+            time.with_subcharges(0xc),
+            Ok(LightningTime::from_parts(8, 0, 0, 0, 0xc))
+        );
+        assert_eq!(
+            time.with_bolts(0xf)
+                .and_then(|t| t.with_zaps(3))
+                .and_then(|t| t.with_sparks(0xa))
+                .and_then(|t| t.with_charges(8))
+                .and_then(|t| t.with_subcharges(0xc)),
+            Ok(LightningTime::from_parts(0xf, 3, 0xa, 8, 0xc))
+        );
+
+        for (result, component) in [
+            (time.with_bolts(16), "bolts"),
+            (time.with_zaps(16), "zaps"),
+            (time.with_sparks(16), "sparks"),
+            (time.with_charges(16), "charges"),
+            (time.with_subcharges(16), "subcharges"),
+        ] {
+            assert_eq!(
+                result,
+                Err(Error::DigitOutOfRange {
+                    component,
+                    value: 16
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn clamped() {
+        assert_eq!(
+            LightningTime::new(99, 99, 99, 99).clamped(),
+            LightningTime::new(15, 15, 15, 15)
+        );
+        assert_eq!(
+            LightningTime::from_parts(8, 20, 0, 3, 16).clamped(),
+            LightningTime::from_parts(8, 15, 0, 3, 15)
+        );
+
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(time.clamped(), time);
+    }
+
+    #[test]
+    fn colors_exhaustive() {
+        use crate::{LightningChannel, LightningChannels, LightningStep, LightningTimeColorConfig};
+
+        let place = |channel, dynamic, (a, b)| match channel {
+            LightningChannel::Red => Srgb::new(dynamic, a, b),
+            LightningChannel::Green => Srgb::new(a, dynamic, b),
+            LightningChannel::Blue => Srgb::new(a, b, dynamic),
+        };
+        let config = LightningTimeColorConfig::builder()
+            .channels(LightningChannels {
+                bolt: LightningChannel::Blue,
+                zap: LightningChannel::Red,
+                spark: LightningChannel::Green,
+            })
+            .build();
+
+        for config in [LightningTimeColorConfig::default(), config] {
+            for time in LightningTime::iter_day(LightningStep::Subcharge) {
+                let channels = &config.channels;
+                assert_eq!(
+                    time.colors(&config),
+                    LightningTimeColors {
+                        bolt: place(
+                            channels.bolt,
+                            time.bolts * 16 + time.zaps,
+                            (config.bolt.0, config.bolt.1)
+                        ),
+                        zap: place(
+                            channels.zap,
+                            time.zaps * 16 + time.sparks,
+                            (config.zap.0, config.zap.1)
+                        ),
+                        spark: place(
+                            channels.spark,
+                            time.sparks * 16 + time.charges,
+                            (config.spark.0, config.spark.1)
+                        ),
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn from_naive_times() {
+        let times: [NaiveTime; 64] = core::array::from_fn(|i| {
+            NaiveTime::from_num_seconds_from_midnight_opt(i as u32 * 1349, i as u32 * 15_625_007)
+                .unwrap()
+        });
+        let mut out = [LightningTime::default(); 64];
+        LightningTime::from_naive_times(&times, &mut out);
+
+        for (time, lightning) in times.into_iter().zip(out) {
+            assert_eq!(lightning, LightningTime::from(time));
+        }
+    }
+
+    #[test]
+    fn pack() {
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(time.pack(), 0xf3a8c);
+        assert_eq!(LightningTime::unpack(0xf3a8c), Ok(time));
+        assert_eq!(LightningTime::new(8, 0, 0, 0).pack(), 0x80000);
+        assert_eq!(
+            LightningTime::from_parts(0x1f, 0, 0, 0, 0x12).pack(),
+            0xf0002
+        );
+        assert_eq!(LightningTime::unpack(0x100000), Err(Error::OutOfDayRange));
+
+        for time in LightningTime::iter_day(crate::LightningStep::Charge) {
+            assert_eq!(LightningTime::unpack(time.pack()), Ok(time));
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(time.to_bytes(), [0x0f, 0x3a, 0x8c]);
+        assert_eq!(LightningTime::from_bytes([0x0f, 0x3a, 0x8c]), Ok(time));
+        assert_eq!(LightningTime::new(8, 0, 0, 0).to_bytes(), [0x08, 0, 0]);
+        assert_eq!(
+            LightningTime::from_bytes([0x10, 0, 0]),
+            Err(Error::OutOfDayRange)
+        );
+
+        for time in LightningTime::iter_day(crate::LightningStep::Charge) {
+            assert_eq!(LightningTime::from_bytes(time.to_bytes()), Ok(time));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn padded_string() {
+        assert_eq!(
+            LightningTime::new(8, 0, 0, 0).to_padded_string(),
+            "08~00~00|00"
+        );
+        assert_eq!(
+            LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc).to_padded_string(),
+            "0f~03~0a|8c"
+        );
+
+        for time in LightningTime::iter_day(crate::LightningStep::Charge) {
+            let padded = time.to_padded_string();
+            assert_eq!(padded.len(), 11);
+            assert_eq!(LightningTime::parse_normalized(&padded), Ok(time));
+        }
+    }
+
+    #[test]
+    fn angles() {
+        let noon = LightningTime::new(8, 0, 0, 0);
+        assert_eq!(noon.bolt_angle(), 180.);
+        assert_eq!(noon.zap_angle(), 0.);
+        assert_eq!(noon.spark_angle(), 0.);
+
+        let time = LightningTime::new(4, 8, 0, 0);
+        assert_eq!(time.bolt_angle(), 90. + 11.25);
+        assert_eq!(time.zap_angle(), 180.);
+
+        let time = LightningTime::from_parts(0, 0, 0, 4, 8);
+        assert_eq!(time.charge_angle(), 90. + 11.25);
+        assert_eq!(time.subcharge_angle(), 180.);
+        assert_eq!(LightningTime::default().bolt_angle(), 0.);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn into_duration() {
+        use chrono::Duration;
+
+        assert_eq!(
+            Duration::from(LightningTime::new(8, 0, 0, 0)),
+            Duration::hours(12)
+        );
+        assert_eq!(
+            Duration::from(LightningTime::new(8, 0, 0, 0)) + Duration::hours(3),
+            Duration::from(LightningTime::new(0xa, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn pattern_compiles() {
+        let re = crate::regex();
+        assert_eq!(re.as_str(), LightningTime::pattern());
+        for name in ["bolt", "zap", "spark", "charge", "subcharge"] {
+            assert!(re.capture_names().flatten().any(|n| n == name));
+        }
+    }
+
+    #[test]
+    fn approx_eq() {
+        let time = LightningTime::new(8, 0, 0, 0);
+        let near = LightningTime::from_parts(8, 0, 0, 0, 2);
+        assert!(time.approx_eq(&time, 0));
+        assert!(time.approx_eq(&near, 2));
+        assert!(near.approx_eq(&time, 2));
+        assert!(!time.approx_eq(&near, 1));
+
+        let midnight = LightningTime::default();
+        let before = LightningTime::from_parts(0xf, 0xf, 0xf, 0xf, 0xf);
+        assert!(midnight.approx_eq(&before, 1));
+        assert!(before.approx_eq(&midnight, 1));
+        assert!(!midnight.approx_eq(&before, 0));
+        assert!(!midnight.approx_eq(&time, LightningTime::SUBCHARGES_PER_DAY / 2 - 1));
+    }
+
+    #[test]
+    fn from_str_strict() {
+        assert_eq!(
+            LightningTime::from_str_strict("8~0~0|00"),
+            Ok(LightningTime::new(8, 0, 0, 0))
+        );
+        assert_eq!(
+            LightningTime::from_str_strict("F~3~A|8C"),
+            Ok(LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc))
+        );
+        assert_eq!(
+            LightningTime::from_str_strict("8~0~0"),
+            Err(Error::InvalidFormat { position: 5 })
+        );
+        assert_eq!(
+            LightningTime::from_str_strict("8~0~0|8"),
+            Err(Error::InvalidFormat { position: 7 })
+        );
+        assert_eq!(
+            LightningTime::from_str_strict("8~0~0|000"),
+            Err(Error::InvalidFormat { position: 8 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn to_hms() {
+        assert_eq!(
+            LightningTime::new(8, 0, 0, 0xa).to_hms(),
+            (12, 0, 13, 183_593_750)
+        );
+
+        for time in LightningTime::iter_day(crate::LightningStep::Charge) {
+            let naive = NaiveTime::from(time);
+            assert_eq!(
+                time.to_hms(),
+                (
+                    naive.hour(),
+                    naive.minute(),
+                    naive.second(),
+                    naive.nanosecond()
+                )
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn snap_naive_time() {
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        assert_eq!(
+            LightningTime::snap_naive_time(NaiveTime::from_hms_milli_opt(12, 0, 0, 2).unwrap()),
+            noon
+        );
+        assert_eq!(
+            LightningTime::snap_naive_time(NaiveTime::from_hms_milli_opt(11, 59, 59, 998).unwrap()),
+            noon
+        );
+        assert_eq!(
+            LightningTime::snap_naive_time(NaiveTime::from_hms_milli_opt(12, 0, 0, 80).unwrap()),
+            NaiveTime::from_hms_nano_opt(12, 0, 0, 82_397_460).unwrap()
+        );
+        assert_eq!(
+            LightningTime::snap_naive_time(NaiveTime::from_hms_milli_opt(23, 59, 59, 990).unwrap()),
+            NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn blended() {
+        let config = crate::LightningTimeColorConfig::default();
+        assert_eq!(
+            LightningTime::new(8, 0, 0, 0).colors(&config).blended(),
+            Srgb::new(141, 98, 71)
+        );
+
+        let white = LightningTimeColors {
+            bolt: Srgb::new(255, 255, 255),
+            zap: Srgb::new(255, 255, 255),
+            spark: Srgb::new(255, 255, 255),
+        };
+        assert_eq!(white.blended(), Srgb::new(255, 255, 255));
+    }
+
+    #[test]
+    fn colors_full_precision() {
+        let config = crate::LightningTimeColorConfig::default();
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        let colors = time.colors_full_precision(&config);
+        assert_eq!(colors.bolt, time.colors(&config).bolt);
+        assert_eq!(colors.spark, Srgb::new(0x8c, 0x85, 0xa8));
+        assert_eq!(colors.try_from_full_precision(&config), Ok(time));
+
+        for time in LightningTime::iter_day(crate::LightningStep::Subcharge).step_by(7) {
+            assert_eq!(
+                time.colors_full_precision(&config)
+                    .try_from_full_precision(&config),
+                Ok(time)
+            );
+        }
+
+        let mut mismatched = colors;
+        mismatched.spark.red = 0x9c;
+        assert_eq!(
+            mismatched.try_from_full_precision(&config),
+            Err(Error::ColorMismatch)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn leap_second() {
+        let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+        let last = LightningTime::from_parts(0xf, 0xf, 0xf, 0xf, 0xf);
+        assert_eq!(LightningTime::from(leap), last);
+        assert_eq!(
+            LightningTime::from(NaiveTime::from_hms_nano_opt(23, 59, 59, 1_999_999_999).unwrap()),
+            last
+        );
+        assert!(LightningTime::from(leap).as_subcharges() < LightningTime::SUBCHARGES_PER_DAY);
+
+        let noon_leap = NaiveTime::from_hms_nano_opt(11, 59, 59, 1_000_000_000).unwrap();
+        assert_eq!(
+            LightningTime::from(noon_leap),
+            LightningTime::from(NaiveTime::from_hms_nano_opt(11, 59, 59, 999_999_999).unwrap())
+        );
+        assert!(LightningTime::from(noon_leap) < LightningTime::new(8, 0, 0, 0));
+    }
+
+    #[test]
+    fn const_default_config() {
+        use crate::{LightningChannels, LightningTimeColorConfig};
+
+        const THEMES: [LightningTimeColorConfig; 1] = [LightningTimeColorConfig::DEFAULT];
+        assert_eq!(THEMES[0], LightningTimeColorConfig::default());
+        assert_eq!(LightningChannels::DEFAULT, LightningChannels::default());
+    }
+
+    #[test]
+    fn components() {
+        let time = LightningTime::from_parts(0xf, 0x3, 0xa, 0x8, 0xc);
+        assert_eq!(time.components(), [0xf, 0x3, 0xa, 0x8, 0xc]);
+        assert_eq!(LightningTime::from_components(time.components()), Ok(time));
+        assert!(time.into_iter().eq(time.components()));
+        assert_eq!(
+            LightningTime::from_components([0, 0, 0x10, 0, 0]),
+            Err(Error::DigitOutOfRange {
+                component: "sparks",
+                value: 0x10
+            })
+        );
+    }
+
+    #[test]
+    fn highest_changed_level() {
+        use crate::LightningStep;
+
+        let time = LightningTime::from_parts(8, 0, 0, 0, 0);
+        assert_eq!(time.highest_changed_level(&time), None);
+        assert_eq!(
+            LightningTime::from_parts(8, 0, 0, 0, 1).highest_changed_level(&time),
+            Some(LightningStep::Subcharge)
+        );
+        assert_eq!(
+            LightningTime::from_parts(9, 0, 0, 0, 0).highest_changed_level(&time),
+            Some(LightningStep::Bolt)
+        );
+        assert_eq!(
+            LightningTime::from_parts(8, 0, 1, 0, 5).highest_changed_level(&time),
+            Some(LightningStep::Spark)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn martian_sol() {
+        const SOL_MILLIS: u32 = 88_775_244;
+        let half_sol = NaiveTime::from_hms_milli_opt(12, 19, 47, 622).unwrap();
+        let noon = LightningTime::new(8, 0, 0, 0);
+
+        assert_eq!(
+            LightningTime::from_naive_time_with_day_millis(half_sol, SOL_MILLIS),
+            Ok(noon)
+        );
+        assert_eq!(
+            noon.try_into_naive_time_with_day_millis(SOL_MILLIS),
+            Ok(half_sol)
+        );
+        // the last 39 minutes of a sol don't fit in a NaiveTime
+        assert_eq!(
+            LightningTime::new(0xf, 0xf, 0xf, 0xf).try_into_naive_time_with_day_millis(SOL_MILLIS),
+            Err(Error::OutOfDayRange)
+        );
+
+        let earth = NaiveTime::from_hms_opt(18, 0, 0).unwrap();
+        assert_eq!(
+            LightningTime::from_naive_time_with_day_millis(earth, LightningTime::MILLIS_PER_DAY),
+            Ok(LightningTime::from(earth))
+        );
+        assert_eq!(
+            LightningTime::from_naive_time_with_day_millis(earth, 60 * 60 * 1000),
+            Err(Error::OutOfDayRange)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn subcharge_fraction() {
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        assert!(LightningTime::subcharge_fraction(noon).abs() < 1e-9);
+
+        // a subcharge is 82397460.9375ns
+        let halfway = NaiveTime::from_hms_nano_opt(0, 0, 0, 41_198_730).unwrap();
+        assert!((LightningTime::subcharge_fraction(halfway) - 0.5).abs() < 1e-8);
+
+        let just_before = NaiveTime::from_hms_nano_opt(0, 0, 0, 82_397_460).unwrap();
+        assert!(LightningTime::subcharge_fraction(just_before) < 1.0);
+        assert_eq!(LightningTime::from(just_before), LightningTime::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_alternate() {
+        let time = LightningTime::from_parts(0xf, 3, 0xa, 8, 0xc);
+        assert_eq!(format!("{time}"), "f~3~a|8c");
+        assert_eq!(
+            format!("{time:#}"),
+            "15 bolts, 3 zaps, 10 sparks, 8 charges, 12 subcharges"
+        );
+        assert_eq!(format!("{time:#.2}"), "15 bolts, 3 zaps");
+        assert_eq!(
+            format!("{:#}", LightningTime::new(8, 0, 0, 0)),
+            "8 bolts, 0 zaps, 0 sparks, 0 charges, 0 subcharges"
+        );
+    }
+
+    #[test]
+    fn parse_labeled() {
+        let time = LightningTime::from_parts(0xf, 3, 0xa, 8, 0xc);
+        for s in [
+            "15 bolts, 3 zaps, 10 sparks, 8 charges, 12 subcharges",
+            "15 bolts,3 zaps,10 sparks,8 charges,12 subcharges",
+            "  15bolts ,  3   zaps, 10 Sparks,8 charge, 12 SUBCHARGES ",
+        ] {
+            assert_eq!(LightningTime::parse_labeled(s), Ok(time), "{s}");
+        }
+        assert_eq!(
+            LightningTime::parse_labeled("1 bolt, 2 zaps"),
+            Ok(LightningTime::new(1, 2, 0, 0))
+        );
+
+        assert_eq!(
+            LightningTime::parse_labeled("8 bolts, 0 sparks"),
+            Err(Error::InvalidFormat { position: 11 })
+        );
+        assert_eq!(
+            LightningTime::parse_labeled("8 bolts, zaps"),
+            Err(Error::InvalidFormat { position: 9 })
+        );
+        assert_eq!(
+            LightningTime::parse_labeled("16 bolts"),
+            Err(Error::DigitOutOfRange {
+                component: "bolts",
+                value: 16
+            })
+        );
+        assert_eq!(
+            LightningTime::parse_labeled("0 bolts, 0 zaps, 0 sparks, 0 charges, 0 subcharges, 0"),
+            Err(Error::InvalidFormat { position: 50 })
+        );
+        assert_eq!(
+            LightningTime::parse_labeled(""),
+            Err(Error::InvalidFormat { position: 0 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_labeled_round_trip() {
+        let time = LightningTime::from_parts(0xf, 3, 0xa, 8, 0xc);
+        assert_eq!(LightningTime::parse_labeled(&format!("{time:#}")), Ok(time));
+    }
+
+    #[test]
+    fn lerp_colors() {
+        use crate::LightningTimeColorConfig;
+
+        let config = LightningTimeColorConfig::default();
+        let a = LightningTime::new(0xf, 3, 0xa, 8).colors(&config);
+        let b = LightningTime::new(2, 0xc, 5, 0).colors(&config);
+
+        assert_eq!(LightningTimeColors::lerp(a, a, 0.5), a);
+        assert_eq!(LightningTimeColors::lerp(a, b, 0.0), a);
+        assert_eq!(LightningTimeColors::lerp(a, b, 1.0), b);
+        assert_eq!(LightningTimeColors::lerp(a, b, 2.0), b);
+        assert_eq!(LightningTimeColors::lerp(a, b, -1.0), a);
+        assert_eq!(LightningTimeColors::lerp(a, b, f64::NAN), a);
+
+        // halfway between black and white is brighter than the sRGB midpoint
+        let black = LightningTimeColors {
+            bolt: Srgb::new(0, 0, 0),
+            zap: Srgb::new(0, 0, 0),
+            spark: Srgb::new(0, 0, 0),
+        };
+        let white = LightningTimeColors {
+            bolt: Srgb::new(255, 255, 255),
+            zap: Srgb::new(255, 255, 255),
+            spark: Srgb::new(255, 255, 255),
+        };
+        assert_eq!(
+            LightningTimeColors::lerp(black, white, 0.5).bolt,
+            Srgb::new(188, 188, 188)
+        );
+    }
+
+    #[test]
+    fn midnight_and_noon() {
+        assert_eq!(LightningTime::MIDNIGHT, LightningTime::default());
+        assert_eq!(LightningTime::NOON.to_hms(), (12, 0, 0, 0));
+        assert_eq!(LightningTime::NOON.as_fraction_of_day(), 0.5);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn noon_naive_time() {
+        assert_eq!(
+            NaiveTime::from(LightningTime::NOON),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap()
+        );
+        assert_eq!(
+            NaiveTime::from(LightningTime::MIDNIGHT),
+            NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn lightning_colors_iterator() {
+        use crate::{LightningColorExt, LightningTimeColorConfig};
+
+        let config = LightningTimeColorConfig::default();
+        let times = [
+            NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+        ];
+
+        assert!(times.into_iter().lightning_colors(&config).eq([
+            LightningTime::MIDNIGHT.colors(&config),
+            LightningTime::NOON.colors(&config),
+            LightningTime::new(0xc, 0, 0, 0).colors(&config),
+        ]));
     }
 }
@@ -1,9 +1,18 @@
-use std::str::FromStr;
+use std::{
+    io::{self, IsTerminal, Write},
+    str::FromStr,
+    sync::mpsc::{self, RecvTimeoutError},
+    time::Duration,
+};
 
-use chrono::NaiveTime;
-use clap::{Parser, Subcommand};
-use hex::ToHex;
-use lightning_time::{LightningTime, LightningTimeColors};
+use chrono::{DateTime, NaiveDateTime, NaiveTime};
+use clap::{
+    builder::PossibleValuesParser, builder::TypedValueParser, Parser, Subcommand, ValueEnum,
+};
+use lightning_time::{
+    Error, LightningStep, LightningTime, LightningTimeColorConfig, LightningTimeColors,
+};
+use palette::Srgb;
 
 /// A CLI for Lightning Time. Allows for easy conversion to/from ISO 8601. Omit the subcommand to print the current time.
 #[derive(Debug, Parser)]
@@ -11,19 +20,357 @@ use lightning_time::{LightningTime, LightningTimeColors};
 struct Args {
     #[command(subcommand)]
     subcommand: Option<Commands>,
+    /// Prints JSON objects instead of plain text
+    #[arg(long, global = true)]
+    json: bool,
 }
 
 #[derive(Debug, Subcommand)]
 enum Commands {
-    /// Converts Lightning Time to comma-separated hex colors, currently only supports default theme
+    /// Converts Lightning Time to comma-separated hex colors, or space-separated CSS rgb() or hsl() colors
     Colors {
         /// The time to convert to colors. If omitted uses the current time
         time: Option<String>,
+        #[command(flatten)]
+        theme: ThemeArgs,
+        /// The format to print the colors in
+        #[arg(long, value_enum, default_value_t = ColorFormat::Hex)]
+        format: ColorFormat,
+        /// Also prints a swatch of each color when stdout is a terminal
+        #[arg(long)]
+        preview: bool,
+    },
+    /// Recovers Lightning Time, without subcharges, from comma-separated hex colors
+    FromColors {
+        /// The bolt, zap, and spark colors, e.g. "#80a100,#3200d6,#f68500"
+        colors: String,
+        #[command(flatten)]
+        theme: ThemeArgs,
+    },
+    /// Converts Lightning Time from %H:%M:%S%.f (ISO 8601 standard), or the time portion of a full ISO 8601 date-time
+    From {
+        /// The time to convert, or - to convert each line of stdin
+        iso: String,
+        /// Fail on blank or invalid lines from stdin instead of skipping them
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Rounds an ISO 8601 time to the nearest whole Lightning Time level, wrapping to midnight
+    Round {
+        /// The time to round
+        iso: String,
+        /// The level to round to
+        #[arg(long, value_enum)]
+        to: Level,
     },
-    /// Converts Lightning Time from %H:%M:%S%.f (ISO 8601 standard)
-    From { iso: String },
     /// Converts Lightning Time to %H:%M:%S%.f (ISO 8601 standard)
-    To { time: String },
+    To {
+        /// The time to convert, or - to convert each line of stdin
+        time: String,
+        /// Fail on blank or invalid lines from stdin instead of skipping them
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Prints evenly spaced times across the day with their comma-separated hex colors
+    Gradient {
+        /// The number of times to print, starting at midnight
+        #[arg(long, default_value_t = 16, value_parser = clap::value_parser!(u32).range(1..))]
+        steps: u32,
+        #[command(flatten)]
+        theme: ThemeArgs,
+        /// Interpolates colors between subcharges instead of using each time's quantized colors
+        #[arg(long)]
+        interpolated: bool,
+    },
+    /// Prints the signed number of subcharges and the ISO 8601 duration from one time to another
+    Diff {
+        /// The time to start from
+        from: String,
+        /// The time to end at
+        to: String,
+        /// Wraps around midnight, either forward only or by the shortest path in either direction
+        #[arg(long, value_enum)]
+        wrap: Option<Wrap>,
+    },
+    /// Converts a sweep of times to Lightning Time and back, failing if any drifts by a subcharge
+    #[command(hide = true)]
+    Selftest,
+    /// Continuously prints the current time in place until interrupted
+    Watch {
+        /// Milliseconds between updates
+        #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+struct ThemeArgs {
+    /// The color theme to use
+    #[arg(
+        long,
+        default_value = "default",
+        value_parser = PossibleValuesParser::new(LightningTimeColorConfig::PRESETS)
+            .try_map(|name| LightningTimeColorConfig::preset(&name).ok_or("unknown theme"))
+    )]
+    theme: LightningTimeColorConfig,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ColorFormat {
+    Hex,
+    Rgb,
+    Hsl,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Level {
+    Bolt,
+    Zap,
+    Spark,
+    Charge,
+    Subcharge,
+}
+
+impl From<Level> for LightningStep {
+    fn from(value: Level) -> Self {
+        match value {
+            Level::Bolt => Self::Bolt,
+            Level::Zap => Self::Zap,
+            Level::Spark => Self::Spark,
+            Level::Charge => Self::Charge,
+            Level::Subcharge => Self::Subcharge,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Wrap {
+    Forward,
+    Shortest,
+}
+
+fn diff(from: LightningTime, to: LightningTime, wrap: Option<Wrap>) -> (i64, chrono::Duration) {
+    let day = LightningTime::SUBCHARGES_PER_DAY as i64;
+    let mut subcharges = to.as_subcharges() as i64 - from.as_subcharges() as i64;
+    let mut duration = to.duration_since_midnight() - from.duration_since_midnight();
+
+    if wrap.is_some() && subcharges < 0 {
+        subcharges += day;
+        duration = duration + chrono::Duration::days(1);
+    }
+    if let Some(Wrap::Shortest) = wrap {
+        if subcharges > day / 2 {
+            subcharges -= day;
+            duration = duration - chrono::Duration::days(1);
+        }
+    }
+
+    (subcharges, duration)
+}
+
+fn format_colors(colors: &LightningTimeColors, format: ColorFormat) -> [String; 3] {
+    match format {
+        ColorFormat::Hex => colors.to_hex_strings(),
+        ColorFormat::Rgb => [colors.bolt, colors.zap, colors.spark]
+            .map(|c| format!("rgb({},{},{})", c.red, c.green, c.blue)),
+        ColorFormat::Hsl => colors.to_hsl().map(|c| {
+            format!(
+                "hsl({:.1},{:.1}%,{:.1}%)",
+                c.hue.into_positive_degrees(),
+                c.saturation * 100.,
+                c.lightness * 100.
+            )
+        }),
+    }
+}
+
+fn swatches(colors: &LightningTimeColors) -> String {
+    [colors.bolt, colors.zap, colors.spark]
+        .map(|c| format!("\x1b[48;2;{};{};{}m    \x1b[0m", c.red, c.green, c.blue))
+        .join(" ")
+}
+
+// None of the printed strings need escaping, so JSON is written by hand
+fn time_json(time: &LightningTime) -> String {
+    format!(
+        r#"{{"bolts":{},"zaps":{},"sparks":{},"charges":{},"subcharges":{},"string":"{time}"}}"#,
+        time.bolts, time.zaps, time.sparks, time.charges, time.subcharges
+    )
+}
+
+fn colors_json([bolt, zap, spark]: &[String; 3]) -> String {
+    format!(r#"{{"bolt":"{bolt}","zap":"{zap}","spark":"{spark}"}}"#)
+}
+
+fn format_time(time: &LightningTime, json: bool) -> String {
+    if json {
+        time_json(time)
+    } else {
+        time.to_string()
+    }
+}
+
+/// Prints the conversion of `input`, or of each line of stdin if `input` is `-`. Blank and invalid
+/// lines are skipped, with invalid lines reported to stderr, unless `strict` is set.
+fn convert_input(
+    input: &str,
+    strict: bool,
+    convert: impl Fn(&str) -> Result<String, String>,
+) -> Result<(), String> {
+    if input != "-" {
+        println!("{}", convert(input)?);
+        return Ok(());
+    }
+
+    for (i, line) in io::stdin().lines().enumerate() {
+        let line = line.map_err(|e| e.to_string())?;
+        let line = line.trim();
+        if line.is_empty() {
+            if strict {
+                return Err(format!("Line {}: blank line", i + 1));
+            }
+            continue;
+        }
+
+        match convert(line) {
+            Ok(out) => println!("{out}"),
+            Err(e) if strict => return Err(format!("Line {}: {e}", i + 1)),
+            Err(e) => eprintln!("Line {}: {e}", i + 1),
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_time(time: &str) -> Result<LightningTime, String> {
+    LightningTime::from_str(time).map_err(|e| match e {
+        Error::InvalidFormat { position } if position < time.len() => format!(
+            "Failed to parse Lightning Time: {e}, expected X~X~X with an optional |XX, found {:?}",
+            &time[position..]
+        ),
+        _ => format!("Failed to parse Lightning Time: {e}"),
+    })
+}
+
+fn parse_colors(colors: &str) -> Result<LightningTimeColors, String> {
+    let parsed: Vec<_> = colors
+        .split(',')
+        .map(|color| {
+            Srgb::from_str(color.trim()).map_err(|_| format!("Failed to parse hex color {color:?}"))
+        })
+        .collect::<Result<_, _>>()?;
+    let [bolt, zap, spark] = parsed[..] else {
+        return Err(format!(
+            "Expected 3 comma-separated colors, got {}",
+            parsed.len()
+        ));
+    };
+
+    Ok(LightningTimeColors { bolt, zap, spark })
+}
+
+fn parse_iso(iso: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(iso, "%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(iso, "%Y-%m-%dT%H:%M:%S%.f").map(|dt| dt.time()))
+        .or_else(|_| DateTime::parse_from_rfc3339(iso).map(|dt| dt.time()))
+        .map_err(|_| {
+            format!("Failed to parse {iso:?} as an ISO 8601 time (%H:%M:%S%.f) or date-time")
+        })
+}
+
+/// Evenly spaced times across the day starting at midnight, with their colors.
+fn gradient(
+    steps: u32,
+    theme: &LightningTimeColorConfig,
+    interpolated: bool,
+) -> impl Iterator<Item = (LightningTime, LightningTimeColors)> + '_ {
+    (0..steps as u64).map(move |i| {
+        let elapsed = i * LightningTime::SUBCHARGES_PER_DAY as u64;
+        let time = LightningTime::from_subcharges((elapsed / steps as u64) as u32)
+            .expect("step to be within the day");
+
+        let colors = if interpolated {
+            let fraction = (elapsed % steps as u64) as f64 / steps as f64;
+            let [bolt, zap, spark] = time
+                .colors_interpolated(theme, fraction)
+                .map(|c| c.into_format());
+            LightningTimeColors { bolt, zap, spark }
+        } else {
+            time.colors(theme)
+        };
+
+        (time, colors)
+    })
+}
+
+/// The largest error in milliseconds from converting times across the day to Lightning Time and
+/// back, stepping by a prime number of milliseconds to cover varied subcharge offsets.
+fn selftest() -> f64 {
+    (0..86_400_000)
+        .step_by(997)
+        .map(|millis| {
+            let time = NaiveTime::from_num_seconds_from_midnight_opt(
+                millis / 1000,
+                millis % 1000 * 1_000_000,
+            )
+            .unwrap();
+            let round_trip = NaiveTime::from(LightningTime::from(time));
+            (time - round_trip).num_nanoseconds().unwrap().abs() as f64 / 1e6
+        })
+        .fold(0., f64::max)
+}
+
+fn render_frame(out: &mut impl Write, time: LightningTime, json: bool) -> io::Result<()> {
+    if json {
+        writeln!(out, "{}", time_json(&time))?;
+    } else {
+        write!(out, "\r{time}")?;
+    }
+    out.flush()
+}
+
+fn watch(interval: Duration, json: bool) -> Result<(), String> {
+    // Ctrl-C wakes the loop through the channel instead of waiting out the interval
+    let (interrupt, interrupted) = mpsc::channel();
+    ctrlc::set_handler(move || {
+        let _ = interrupt.send(());
+    })
+    .map_err(|e| format!("Failed to handle Ctrl-C: {e}"))?;
+
+    let mut stdout = io::stdout();
+    let _cursor = if json {
+        None
+    } else {
+        Some(HiddenCursor::new().map_err(|e| e.to_string())?)
+    };
+    loop {
+        render_frame(&mut stdout, LightningTime::now(), json).map_err(|e| e.to_string())?;
+        if !matches!(
+            interrupted.recv_timeout(interval),
+            Err(RecvTimeoutError::Timeout)
+        ) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Hides the cursor while rendering in place, showing it again when dropped so that it is restored
+/// however `watch` exits.
+struct HiddenCursor;
+
+impl HiddenCursor {
+    fn new() -> io::Result<Self> {
+        write!(io::stdout(), "\x1b[?25l")?;
+        Ok(Self)
+    }
+}
+
+impl Drop for HiddenCursor {
+    fn drop(&mut self) {
+        let _ = writeln!(io::stdout(), "\x1b[?25h");
+    }
 }
 
 fn main() -> Result<(), String> {
@@ -31,39 +378,125 @@ fn main() -> Result<(), String> {
 
     match args.subcommand {
         Some(cmd) => match cmd {
-            Commands::Colors { time } => {
-                let time = match time
-                    .map(|t| LightningTime::from_str(&t))
-                    .unwrap_or_else(|| Ok(LightningTime::now()))
-                {
-                    Ok(lt) => lt,
-                    Err(e) => {
-                        return Err(format!("Failed to parse Lightning Time: {e}"));
-                    }
+            Commands::Colors {
+                time,
+                theme: ThemeArgs { theme },
+                format,
+                preview,
+            } => {
+                let time = match time {
+                    Some(time) => parse_time(&time)?,
+                    None => LightningTime::now(),
                 };
 
-                let LightningTimeColors { bolt, zap, spark } = time.colors(&Default::default());
-                println!(
-                    "#{},#{},#{}",
-                    bolt.encode_hex::<String>(),
-                    zap.encode_hex::<String>(),
-                    spark.encode_hex::<String>()
-                );
+                let lightning_colors = time.colors(&theme);
+                let colors = format_colors(&lightning_colors, format);
+                if args.json {
+                    println!("{}", colors_json(&colors));
+                } else if let ColorFormat::Hex = format {
+                    println!("{}", colors.join(","));
+                } else {
+                    println!("{}", colors.join(" "));
+                }
+
+                if preview && !args.json && io::stdout().is_terminal() {
+                    println!("{}", swatches(&lightning_colors));
+                }
+            }
+            Commands::FromColors {
+                colors,
+                theme: ThemeArgs { theme },
+            } => {
+                let time = parse_colors(&colors)?
+                    .try_to_lightning_time(&theme)
+                    .map_err(|e| format!("Failed to recover Lightning Time: {e}"))?;
+                println!("{}", format_time(&time, args.json));
             }
-            Commands::From { iso } => {
-                let parsed = chrono::NaiveTime::parse_from_str(&iso, "%H:%M:%S%.f")
-                    .map_err(|e| e.to_string())?;
-                println!("{}", LightningTime::from(parsed));
+            Commands::From { iso, strict } => convert_input(&iso, strict, |iso| {
+                Ok(format_time(
+                    &LightningTime::from(parse_iso(iso)?),
+                    args.json,
+                ))
+            })?,
+            Commands::Round { iso, to } => {
+                let time = NaiveTime::from(LightningTime::from_naive_time_rounded_to(
+                    parse_iso(&iso)?,
+                    to.into(),
+                ));
+                if args.json {
+                    println!(r#"{{"time":"{time}"}}"#);
+                } else {
+                    println!("{time}");
+                }
             }
-            Commands::To { time } => {
-                let parsed = LightningTime::from_str(&time)
-                    .map_err(|e| format!("Failed to parse Lightning Time: {e}"))?;
+            Commands::To { time, strict } => convert_input(&time, strict, |time| {
+                let time = NaiveTime::from(parse_time(time)?);
+                Ok(if args.json {
+                    format!(r#"{{"time":"{time}"}}"#)
+                } else {
+                    time.to_string()
+                })
+            })?,
+            Commands::Gradient {
+                steps,
+                theme: ThemeArgs { theme },
+                interpolated,
+            } => {
+                for (time, colors) in gradient(steps, &theme, interpolated) {
+                    let colors = colors.to_hex_strings();
+                    if args.json {
+                        println!(r#"{{"time":"{time}","colors":{}}}"#, colors_json(&colors));
+                    } else {
+                        println!("{time} {}", colors.join(","));
+                    }
+                }
+            }
+            Commands::Diff { from, to, wrap } => {
+                let (subcharges, duration) = diff(parse_time(&from)?, parse_time(&to)?, wrap);
 
-                println!("{}", NaiveTime::from(parsed));
+                if args.json {
+                    println!(r#"{{"subcharges":{subcharges},"duration":"{duration}"}}"#);
+                } else {
+                    println!("{subcharges} {duration}");
+                }
+            }
+            Commands::Selftest => {
+                let max_error = selftest();
+                if args.json {
+                    println!(r#"{{"max_error_ms":{max_error}}}"#);
+                } else {
+                    println!("Maximum error: {max_error}ms");
+                }
+                if max_error > LightningTime::MILLIS_PER_SUBCHARGE {
+                    return Err(format!(
+                        "Round trip error exceeds one subcharge ({}ms)",
+                        LightningTime::MILLIS_PER_SUBCHARGE
+                    ));
+                }
             }
+            Commands::Watch { interval } => watch(Duration::from_millis(interval), args.json)?,
         },
-        None => println!("{}", LightningTime::now()),
+        None => println!("{}", format_time(&LightningTime::now(), args.json)),
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use lightning_time::LightningTime;
+
+    use crate::render_frame;
+
+    #[test]
+    fn watch_frame() {
+        let mut out = Vec::new();
+        render_frame(&mut out, LightningTime::now(), false).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let frame = out.strip_prefix('\r').unwrap();
+        assert!(LightningTime::from_str(frame).is_ok());
+    }
+}
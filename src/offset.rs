@@ -0,0 +1,81 @@
+use crate::LightningTime;
+
+/// A signed number of subcharges relative to some time, e.g. `LightningOffset(-30)` for 30
+/// subcharges earlier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct LightningOffset(pub i32);
+
+fn offset(time: LightningTime, subcharges: i64) -> LightningTime {
+    let day = LightningTime::SUBCHARGES_PER_DAY as i64;
+    let elapsed = (time.as_subcharges() as i64 + subcharges).rem_euclid(day);
+    LightningTime::from_elapsed_subcharges(elapsed as u32)
+}
+
+/// Moves the time by the offset, wrapping around at midnight in either direction.
+impl core::ops::Add<LightningOffset> for LightningTime {
+    type Output = Self;
+
+    fn add(self, rhs: LightningOffset) -> Self::Output {
+        offset(self, rhs.0 as i64)
+    }
+}
+
+impl core::ops::AddAssign<LightningOffset> for LightningTime {
+    fn add_assign(&mut self, rhs: LightningOffset) {
+        *self = *self + rhs;
+    }
+}
+
+/// Moves the time back by the offset, wrapping around at midnight in either direction.
+impl core::ops::Sub<LightningOffset> for LightningTime {
+    type Output = Self;
+
+    fn sub(self, rhs: LightningOffset) -> Self::Output {
+        offset(self, -(rhs.0 as i64))
+    }
+}
+
+impl core::ops::SubAssign<LightningOffset> for LightningTime {
+    fn sub_assign(&mut self, rhs: LightningOffset) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LightningOffset, LightningTime};
+
+    #[test]
+    fn positive_offset() {
+        let time = LightningTime::new(8, 0, 0, 0);
+        assert_eq!(
+            time + LightningOffset(30),
+            LightningTime::from_parts(8, 0, 0, 1, 0xe)
+        );
+
+        let mut late = LightningTime::from_parts(0xf, 0xf, 0xf, 0xf, 0xf);
+        late += LightningOffset(2);
+        assert_eq!(late, LightningTime::from_parts(0, 0, 0, 0, 1));
+    }
+
+    #[test]
+    fn negative_offset() {
+        let time = LightningTime::from_parts(0, 0, 0, 0, 1);
+        assert_eq!(
+            time + LightningOffset(-2),
+            LightningTime::from_parts(0xf, 0xf, 0xf, 0xf, 0xf)
+        );
+        assert_eq!(
+            time - LightningOffset(2),
+            LightningTime::from_parts(0xf, 0xf, 0xf, 0xf, 0xf)
+        );
+
+        let mut noon = LightningTime::new(8, 0, 0, 0);
+        noon -= LightningOffset(-0x10000);
+        assert_eq!(noon, LightningTime::new(9, 0, 0, 0));
+        assert_eq!(
+            noon + LightningOffset(i32::MIN),
+            noon - LightningOffset(i32::MIN)
+        );
+    }
+}
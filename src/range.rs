@@ -0,0 +1,128 @@
+use crate::LightningTime;
+
+/// A half-open range of times from `start` up to but not including `end`. If `start` is after `end`
+/// the range wraps past midnight, and if they are equal the range is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LightningRange {
+    pub start: LightningTime,
+    pub end: LightningTime,
+}
+
+impl LightningRange {
+    pub const fn new(start: LightningTime, end: LightningTime) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether `t` is in the range, including `start` but not `end`.
+    pub fn contains(&self, t: LightningTime) -> bool {
+        if self.start <= self.end {
+            self.start <= t && t < self.end
+        } else {
+            self.start <= t || t < self.end
+        }
+    }
+
+    /// Like [`LightningRange::contains`], but includes `end` too, so equal endpoints contain just
+    /// that time.
+    pub fn contains_inclusive(&self, t: LightningTime) -> bool {
+        self.contains(t) || t == self.end
+    }
+
+    /// Like [`LightningRange::contains`], but excludes `start` too.
+    pub fn contains_exclusive(&self, t: LightningTime) -> bool {
+        self.contains(t) && t != self.start
+    }
+
+    /// The number of subcharges covered by the range.
+    pub fn duration(&self) -> u32 {
+        let day = LightningTime::SUBCHARGES_PER_DAY;
+        (self.end.as_subcharges() % day + day - self.start.as_subcharges() % day) % day
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LightningRange, LightningTime};
+
+    #[test]
+    fn normal_range() {
+        let business = LightningRange::new(
+            LightningTime::new(6, 0, 0, 0),
+            LightningTime::new(0xc, 0, 0, 0),
+        );
+
+        assert!(business.contains(LightningTime::new(6, 0, 0, 0)));
+        assert!(business.contains(LightningTime::new(8, 0, 0, 0)));
+        assert!(business.contains(LightningTime::from_parts(0xb, 0xf, 0xf, 0xf, 0xf)));
+        assert!(!business.contains(LightningTime::new(0xc, 0, 0, 0)));
+        assert!(!business.contains(LightningTime::new(2, 0, 0, 0)));
+        assert_eq!(business.duration(), 6 * 0x10000);
+    }
+
+    #[test]
+    fn wrapping_range() {
+        let night = LightningRange::new(
+            LightningTime::new(0xe, 0, 0, 0),
+            LightningTime::new(2, 0, 0, 0),
+        );
+
+        assert!(night.contains(LightningTime::new(0xf, 0, 0, 0)));
+        assert!(night.contains(LightningTime::default()));
+        assert!(night.contains(LightningTime::new(1, 0xf, 0, 0)));
+        assert!(!night.contains(LightningTime::new(2, 0, 0, 0)));
+        assert!(!night.contains(LightningTime::new(8, 0, 0, 0)));
+        assert_eq!(night.duration(), 4 * 0x10000);
+    }
+
+    #[test]
+    fn empty_range() {
+        let empty = LightningRange::new(
+            LightningTime::new(8, 0, 0, 0),
+            LightningTime::new(8, 0, 0, 0),
+        );
+
+        assert!(!empty.contains(LightningTime::new(8, 0, 0, 0)));
+        assert_eq!(empty.duration(), 0);
+    }
+
+    #[test]
+    fn endpoint_inclusivity() {
+        let morning = LightningRange::new(
+            LightningTime::new(6, 0, 0, 0),
+            LightningTime::new(8, 0, 0, 0),
+        );
+        let start = LightningTime::new(6, 0, 0, 0);
+        let end = LightningTime::new(8, 0, 0, 0);
+        let before_end = LightningTime::from_parts(7, 0xf, 0xf, 0xf, 0xf);
+
+        assert!(!morning.contains(end));
+        assert!(morning.contains_inclusive(end));
+        assert!(!morning.contains_exclusive(end));
+
+        assert!(morning.contains(start));
+        assert!(morning.contains_inclusive(start));
+        assert!(!morning.contains_exclusive(start));
+
+        assert!(morning.contains_inclusive(before_end));
+        assert!(morning.contains_exclusive(before_end));
+
+        let night = LightningRange::new(end, start);
+        assert!(night.contains_inclusive(start));
+        assert!(!night.contains_exclusive(end));
+
+        let point = LightningRange::new(end, end);
+        assert!(point.contains_inclusive(end));
+        assert!(!point.contains_exclusive(end));
+    }
+
+    #[test]
+    fn out_of_range_endpoint() {
+        let range = LightningRange::new(
+            LightningTime::from_parts(20, 0, 0, 0, 0),
+            LightningTime::MIDNIGHT,
+        );
+
+        // 20 bolts wraps to 4 bolts
+        assert_eq!(range.duration(), 12 * 0x10000);
+    }
+}
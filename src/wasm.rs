@@ -0,0 +1,76 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{LightningTime, LightningTimeColorConfig};
+
+/// A [`LightningTime`] exported to JavaScript as `LightningTime`.
+#[wasm_bindgen(js_name = LightningTime)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmLightningTime(LightningTime);
+
+#[wasm_bindgen(js_class = LightningTime)]
+impl WasmLightningTime {
+    /// The current local time.
+    pub fn now() -> Self {
+        Self(LightningTime::now())
+    }
+
+    /// Parses a time like `8~0~0|00`, throwing an `Error` if it's invalid.
+    #[wasm_bindgen(js_name = fromString)]
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<WasmLightningTime, JsError> {
+        s.parse()
+            .map(Self)
+            .map_err(|e: crate::Error| JsError::new(&e.to_string()))
+    }
+
+    /// Formats the time like `8~0~0|00`.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// The bolt, zap, and spark colors as `#rrggbb` strings, using a theme from
+    /// [`LightningTimeColorConfig::PRESETS`]. Throws an `Error` if the theme is unknown.
+    pub fn colors(&self, theme: &str) -> Result<Vec<String>, JsError> {
+        let config = LightningTimeColorConfig::preset(theme)
+            .ok_or_else(|| JsError::new(&format!("unknown theme {theme:?}")))?;
+        Ok(self.0.colors(&config).to_hex_strings().into())
+    }
+}
+
+impl From<LightningTime> for WasmLightningTime {
+    fn from(time: LightningTime) -> Self {
+        Self(time)
+    }
+}
+
+impl From<WasmLightningTime> for LightningTime {
+    fn from(time: WasmLightningTime) -> Self {
+        time.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Errors are only constructible on wasm targets, so they're covered by tests/wasm.rs
+    #[test]
+    fn round_trip() {
+        let time = WasmLightningTime::from_str("8~4~2|10").unwrap();
+        assert_eq!(time.to_string(), "8~4~2|10");
+        assert_eq!(LightningTime::from(time), LightningTime::new(8, 4, 2, 1));
+    }
+
+    #[test]
+    fn colors() {
+        let time = WasmLightningTime::from(LightningTime::new(8, 4, 2, 1));
+        assert_eq!(
+            time.colors("default").unwrap(),
+            LightningTime::new(8, 4, 2, 1)
+                .colors(&LightningTimeColorConfig::default())
+                .to_hex_strings()
+        );
+    }
+}
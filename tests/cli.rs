@@ -0,0 +1,276 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_lightning-time"))
+        .args(args)
+        .output()
+        .expect("CLI to run")
+}
+
+fn run_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_lightning-time"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("CLI to run");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = run(args);
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn from_time() {
+    assert_eq!(stdout(&["from", "12:00:00"]), "8~0~0|00\n");
+}
+
+#[test]
+fn from_date_time() {
+    assert_eq!(stdout(&["from", "2024-01-01T12:00:00"]), "8~0~0|00\n");
+    assert_eq!(stdout(&["from", "2024-01-01T18:00:00.000"]), "c~0~0|00\n");
+    assert_eq!(stdout(&["from", "2024-01-01T12:00:00+02:00"]), "8~0~0|00\n");
+}
+
+#[test]
+fn from_invalid() {
+    let output = run(&["from", "noon"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("noon"));
+}
+
+#[test]
+fn colors_formats() {
+    assert_eq!(stdout(&["colors", "8~0~0"]), "#80a100,#3200d6,#f68500\n");
+    assert_eq!(
+        stdout(&["colors", "8~0~0", "--format", "hex"]),
+        "#80a100,#3200d6,#f68500\n"
+    );
+    assert_eq!(
+        stdout(&["colors", "8~0~0", "--format", "rgb"]),
+        "rgb(128,161,0) rgb(50,0,214) rgb(246,133,0)\n"
+    );
+    assert_eq!(
+        stdout(&["colors", "8~0~0", "--format", "hsl"]),
+        "hsl(72.3,100.0%,31.6%) hsl(254.0,100.0%,42.0%) hsl(32.4,100.0%,48.2%)\n"
+    );
+}
+
+#[test]
+fn colors_theme() {
+    let default = stdout(&["colors", "8~0~0"]);
+    assert_eq!(stdout(&["colors", "8~0~0", "--theme", "default"]), default);
+    assert_ne!(
+        stdout(&["colors", "8~0~0", "--theme", "monochrome"]),
+        default
+    );
+    assert_eq!(
+        stdout(&["colors", "8~0~0", "--theme", "monochrome"]),
+        "#008000,#000000,#000000\n"
+    );
+
+    let output = run(&["colors", "8~0~0", "--theme", "monochrom"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("default, solarized, monochrome"),
+        "{stderr}"
+    );
+}
+
+fn json(args: &[&str]) -> serde_json::Value {
+    serde_json::from_str(&stdout(args)).expect("valid JSON")
+}
+
+#[test]
+fn json_output() {
+    assert_eq!(
+        json(&["--json", "from", "12:00:00"]),
+        serde_json::json!({
+            "bolts": 8,
+            "zaps": 0,
+            "sparks": 0,
+            "charges": 0,
+            "subcharges": 0,
+            "string": "8~0~0|00",
+        })
+    );
+    assert_eq!(
+        json(&["to", "8~0~0", "--json"]),
+        serde_json::json!({ "time": "12:00:00" })
+    );
+    assert_eq!(
+        json(&["colors", "8~0~0", "--json"]),
+        serde_json::json!({ "bolt": "#80a100", "zap": "#3200d6", "spark": "#f68500" })
+    );
+    assert_eq!(
+        json(&["colors", "8~0~0", "--format", "rgb", "--json"])["bolt"],
+        "rgb(128,161,0)"
+    );
+    assert!(json(&["--json"])["string"].is_string());
+}
+
+#[test]
+fn stdin_batch() {
+    let output = run_stdin(
+        &["from", "-"],
+        "12:00:00\n\n2024-01-01T18:00:00\nnoon\n00:00:00\n",
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "8~0~0|00\nc~0~0|00\n0~0~0|00\n"
+    );
+    assert!(String::from_utf8(output.stderr).unwrap().contains("Line 4"));
+
+    let output = run_stdin(&["to", "-"], "8~0~0\nc~0~0|00\n");
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "12:00:00\n18:00:00\n"
+    );
+}
+
+#[test]
+fn stdin_batch_strict() {
+    let output = run_stdin(&["from", "-", "--strict"], "12:00:00\nnoon\n00:00:00\n");
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "8~0~0|00\n");
+
+    let output = run_stdin(&["to", "-", "--strict"], "8~0~0\n\nc~0~0\n");
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "12:00:00\n");
+}
+
+#[test]
+fn colors_preview() {
+    let plain = stdout(&["colors", "8~0~0"]);
+    assert_eq!(plain, "#80a100,#3200d6,#f68500\n");
+    // Swatches are only printed to a terminal
+    assert_eq!(stdout(&["colors", "8~0~0", "--preview"]), plain);
+}
+
+#[test]
+fn gradient() {
+    let out = stdout(&["gradient", "--steps", "16"]);
+    let lines: Vec<_> = out.lines().collect();
+    assert_eq!(lines.len(), 16);
+    assert_eq!(lines[0], "0~0~0|00 #00a100,#3200d6,#f68500");
+    assert_eq!(lines[8], "8~0~0|00 #80a100,#3200d6,#f68500");
+
+    let out = stdout(&["gradient", "--steps", "3", "--interpolated"]);
+    let lines: Vec<_> = out.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("0~0~0|00 "));
+    assert!(lines[1].starts_with("5~5~5|55 "));
+
+    assert!(!run(&["gradient", "--steps", "0"]).status.success());
+}
+
+#[test]
+fn watch_zero_interval() {
+    assert!(!run(&["watch", "--interval", "0"]).status.success());
+}
+
+#[test]
+fn diff() {
+    assert_eq!(stdout(&["diff", "8~0~0", "c~0~0"]), "262144 PT21600S\n");
+    assert_eq!(stdout(&["diff", "c~0~0", "8~0~0"]), "-262144 -PT21600S\n");
+    assert_eq!(stdout(&["diff", "0~0~0", "0~0~0|01"]), "1 PT0.082397460S\n");
+}
+
+#[test]
+fn diff_wrap() {
+    assert_eq!(
+        stdout(&["diff", "f~0~0", "1~0~0", "--wrap", "forward"]),
+        "131072 PT10800S\n"
+    );
+    assert_eq!(
+        stdout(&["diff", "f~0~0", "1~0~0", "--wrap", "shortest"]),
+        "131072 PT10800S\n"
+    );
+    assert_eq!(
+        stdout(&["diff", "1~0~0", "f~0~0", "--wrap", "forward"]),
+        "917504 PT75600S\n"
+    );
+    assert_eq!(
+        stdout(&["diff", "1~0~0", "f~0~0", "--wrap", "shortest"]),
+        "-131072 -PT10800S\n"
+    );
+    assert_eq!(stdout(&["diff", "f~0~0", "1~0~0"]), "-917504 -PT75600S\n");
+}
+
+#[test]
+fn selftest() {
+    assert!(stdout(&["selftest"]).starts_with("Maximum error: "));
+    let max_error = json(&["--json", "selftest"])["max_error_ms"].as_f64();
+    assert!(
+        max_error.is_some_and(|e| e <= lightning_time::LightningTime::MILLIS_PER_SUBCHARGE),
+        "{max_error:?}"
+    );
+    assert!(!stdout(&["--help"]).contains("selftest"));
+}
+
+#[test]
+fn to_invalid() {
+    let output = run(&["to", "8~0~0-00"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("position 5"), "{stderr}");
+    assert!(stderr.contains("-00"), "{stderr}");
+    assert!(stderr.contains("expected X~X~X"), "{stderr}");
+}
+
+#[test]
+fn from_colors() {
+    for (time, theme) in [("f~3~a|80", "default"), ("8~0~0|00", "solarized")] {
+        let colors = stdout(&["colors", time, "--theme", theme]);
+        assert_eq!(
+            stdout(&["from-colors", colors.trim(), "--theme", theme]),
+            format!("{time}\n")
+        );
+    }
+
+    let output = run(&[
+        "from-colors",
+        "#80a100,#3200d6,#f68500",
+        "--theme",
+        "solarized",
+    ]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("not produced by the given color config"));
+    assert!(!run(&["from-colors", "#80a100,#3200d6"]).status.success());
+    assert!(!run(&["from-colors", "#80a100,#3200d6,nope"])
+        .status
+        .success());
+}
+
+#[test]
+fn round() {
+    assert_eq!(stdout(&["round", "12:40:00", "--to", "bolt"]), "12:00:00\n");
+    assert_eq!(stdout(&["round", "12:50:00", "--to", "bolt"]), "13:30:00\n");
+    assert_eq!(stdout(&["round", "23:50:00", "--to", "bolt"]), "00:00:00\n");
+    assert_eq!(
+        stdout(&["round", "12:00:10", "--to", "spark"]),
+        "12:00:00\n"
+    );
+    assert_eq!(
+        stdout(&["round", "12:00:11", "--to", "spark"]),
+        "12:00:21.093750\n"
+    );
+}
@@ -0,0 +1,52 @@
+use std::str::FromStr;
+
+use lightning_time::{LightningFormat, LightningTime};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn from_str_round_trips(s in "\\PC*") {
+        if let Ok(time) = LightningTime::from_str(&s) {
+            prop_assert_eq!(LightningTime::from_str(&time.to_string()), Ok(time));
+        }
+    }
+
+    #[test]
+    fn from_str_near_grammar(s in "[0-9a-fA-F~|g ]{0,10}") {
+        if let Ok(time) = LightningTime::from_str(&s) {
+            prop_assert_eq!(LightningTime::from_str(&time.to_string()), Ok(time));
+            prop_assert_eq!(LightningTime::from_str_strict(&time.to_string()), Ok(time));
+        }
+    }
+
+    #[test]
+    fn parse_normalized_never_panics(s in "[0-9a-fA-F~|é]{0,12}") {
+        if let Ok(time) = LightningTime::parse_normalized(&s) {
+            prop_assert_eq!(LightningTime::from_str(&time.to_string()), Ok(time));
+        }
+    }
+
+    #[test]
+    fn custom_format_round_trips(s in "[0-9a-f:·]{0,10}") {
+        let format = LightningFormat {
+            group_separator: ':',
+            charge_separator: '·',
+        };
+        if let Ok(time) = format.parse(&s) {
+            prop_assert_eq!(format.parse(&time.format_with(&format)), Ok(time));
+        }
+    }
+
+    #[test]
+    fn find_never_panics(s in "\\PC*") {
+        if let Some(time) = LightningTime::find(&s) {
+            prop_assert_eq!(LightningTime::from_str(&time.to_string()), Ok(time));
+        }
+    }
+
+    #[test]
+    fn display_round_trips(subcharges in 0..LightningTime::SUBCHARGES_PER_DAY) {
+        let time = LightningTime::from_subcharges(subcharges).unwrap();
+        prop_assert_eq!(LightningTime::from_str(&time.to_string()), Ok(time));
+    }
+}
@@ -0,0 +1,44 @@
+//! Runs in Node with `wasm-bindgen-test-runner` from `wasm-bindgen-cli` set as the runner:
+//!
+//! ```sh
+//! CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//!     cargo test --target wasm32-unknown-unknown --no-default-features --features wasm --test wasm
+//! ```
+#![cfg(target_arch = "wasm32")]
+
+use lightning_time::{LightningTime, LightningTimeColorConfig, WasmLightningTime};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn round_trip() {
+    let time = WasmLightningTime::from_str("8~4~2|10").unwrap();
+    assert_eq!(time.to_string(), "8~4~2|10");
+    assert_eq!(LightningTime::from(time), LightningTime::new(8, 4, 2, 1));
+}
+
+#[wasm_bindgen_test]
+fn invalid() {
+    assert!(WasmLightningTime::from_str("8~4").is_err());
+    assert!(WasmLightningTime::from(LightningTime::NOON)
+        .colors("unknown")
+        .is_err());
+}
+
+#[wasm_bindgen_test]
+fn colors() {
+    let colors = WasmLightningTime::from(LightningTime::NOON)
+        .colors("default")
+        .unwrap();
+    assert_eq!(
+        colors,
+        LightningTime::NOON
+            .colors(&LightningTimeColorConfig::DEFAULT)
+            .to_hex_strings()
+    );
+}
+
+#[wasm_bindgen_test]
+fn now() {
+    // Goes through `Date` in the host, which panics if chrono's wasmbind feature is missing
+    assert!(WasmLightningTime::now().to_string().contains('~'));
+}